@@ -54,6 +54,15 @@ pub enum PathError {
     
     #[error("Path is not relative: {0}")]
     NotRelative(String),
+    
+    #[error("Path traversal rejected: {0}")]
+    Traversal(String),
+    
+    #[error("Path is not under base: {0}")]
+    NotUnderBase(String),
+    
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 impl PathError {
@@ -96,4 +105,16 @@ impl PathError {
     pub fn not_relative<S: Into<String>>(msg: S) -> Self {
         Self::NotRelative(msg.into())
     }
+    
+    pub fn traversal<S: Into<String>>(msg: S) -> Self {
+        Self::Traversal(msg.into())
+    }
+    
+    pub fn not_under_base<S: Into<String>>(msg: S) -> Self {
+        Self::NotUnderBase(msg.into())
+    }
+    
+    pub fn io(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use serde::{Serialize, Deserialize};
 use crate::core::Result;
 use crate::utils::UtilsError;
@@ -27,6 +27,33 @@ impl ValidatedPath {
         input.parse_path()
     }
     
+    /// Create a new ValidatedPath and lexically normalize it
+    ///
+    /// See [`ValidatedPath::normalize`] for the normalization rules.
+    pub fn new_normalized<T>(input: T) -> Result<Self>
+    where
+        T: PathFromInput,
+    {
+        Ok(input.parse_path()?.normalize())
+    }
+    
+    /// Create a new normalized ValidatedPath that must stay within `base`
+    ///
+    /// Rejects inputs such as `../../etc/passwd` that escape the base directory.
+    pub fn new_within<T, B>(input: T, base: B) -> Result<Self>
+    where
+        T: PathFromInput,
+        B: AsRef<Path>,
+    {
+        let path = Self::new_normalized(input)?;
+        match path.is_within(base.as_ref()) {
+            true => Ok(path),
+            false => Err(UtilsError::Path(PathError::traversal(format!(
+                "{} escapes base directory {}", path.inner.display(), base.as_ref().display()
+            ))).into()),
+        }
+    }
+    
     /// Get the inner PathBuf
     pub fn into_path_buf(self) -> PathBuf {
         self.inner
@@ -62,6 +89,21 @@ impl ValidatedPath {
         self.inner.extension()
     }
     
+    /// Get the file stem (file name without its final extension)
+    pub fn file_stem(&self) -> Option<&OsStr> {
+        self.inner.file_stem()
+    }
+    
+    /// Return a new path with the extension replaced, re-validating the result
+    pub fn with_extension(&self, ext: &str) -> Result<ValidatedPath> {
+        self.inner.with_extension(ext).parse_path()
+    }
+    
+    /// Return a new path with the file name replaced, re-validating the result
+    pub fn with_file_name(&self, name: &str) -> Result<ValidatedPath> {
+        self.inner.with_file_name(name).parse_path()
+    }
+    
     /// Join with another path component
     pub fn join<P: AsRef<Path>>(&self, path: P) -> Result<ValidatedPath> {
         let joined = self.inner.join(path);
@@ -73,6 +115,127 @@ impl ValidatedPath {
         }
     }
     
+    /// Validate `component` on its own, then join it
+    ///
+    /// Unlike `join`, a bad component is reported by itself (e.g. "Path
+    /// contains invalid characters: raw*.dbc") rather than as part of the
+    /// whole joined path.
+    pub fn join_validated<P: AsRef<Path>>(&self, component: P) -> Result<ValidatedPath> {
+        let component = component.as_ref().parse_path()?;
+        self.join(component.as_path())
+    }
+    
+    /// Lexically normalize the path, collapsing `.` and `..` components
+    ///
+    /// Normalization is purely lexical: it never touches the filesystem and
+    /// does not resolve symlinks, so `a/link/..` becomes `a` even if `link`
+    /// points elsewhere. Leading `..` components of relative paths are kept,
+    /// and `..` at the root of an absolute path is dropped.
+    pub fn normalize(&self) -> ValidatedPath {
+        ValidatedPath { inner: normalize_lexically(&self.inner) }
+    }
+    
+    /// Check if the path lies within `base` after lexical normalization of both
+    ///
+    /// Relative paths are not resolved against the current directory, so a
+    /// relative path is never within an absolute base and vice versa.
+    pub fn is_within(&self, base: &Path) -> bool {
+        normalize_lexically(&self.inner).starts_with(normalize_lexically(base))
+    }
+    
+    /// Get the part of this path below `base`, after lexical normalization of both
+    ///
+    /// `/mirror/sih/2024/a.dbc` relative to `/mirror` is `sih/2024/a.dbc`.
+    /// Returns `.` when the paths are equal and `PathError::NotUnderBase` when
+    /// this path is not inside `base`.
+    pub fn relative_to(&self, base: &Path) -> Result<ValidatedPath> {
+        let normalized = normalize_lexically(&self.inner);
+        let base = normalize_lexically(base);
+        match normalized.strip_prefix(&base) {
+            Ok(rest) => Ok(ValidatedPath { inner: non_empty_or_cur_dir(rest) }),
+            Err(_) => Err(UtilsError::Path(PathError::not_under_base(format!(
+                "{} is not under {}", self.inner.display(), base.display()
+            ))).into()),
+        }
+    }
+    
+    /// Remove `prefix` from the start of the path as written, like `Path::strip_prefix`
+    ///
+    /// No normalization is applied, so `a/./b` does not start with `a/b`;
+    /// use `relative_to` for that. Returns `.` when the whole path is stripped.
+    pub fn strip_prefix(&self, prefix: &Path) -> Result<ValidatedPath> {
+        match self.inner.strip_prefix(prefix) {
+            Ok(rest) => Ok(ValidatedPath { inner: non_empty_or_cur_dir(rest) }),
+            Err(_) => Err(UtilsError::Path(PathError::not_under_base(format!(
+                "{} does not start with {}", self.inner.display(), prefix.display()
+            ))).into()),
+        }
+    }
+    
+    /// Read this directory and return the entries that pass path validation
+    ///
+    /// Entries whose paths are not valid UTF-8 are skipped, as are entries
+    /// that fail validation (e.g. names with invalid characters). Filesystem
+    /// failures surface as `PathError::Io`. The result is sorted by path.
+    pub fn read_dir_validated(&self) -> Result<Vec<ValidatedPath>> {
+        let entries = std::fs::read_dir(&self.inner)
+            .map_err(|e| UtilsError::Path(PathError::io(e)))?;
+        
+        let mut paths = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(|e| UtilsError::Path(PathError::io(e)))?.path();
+            if path.to_str().is_none() {
+                continue;
+            }
+            if let Ok(validated) = path.parse_path() {
+                paths.push(validated);
+            }
+        }
+        
+        paths.sort_by(|a, b| a.inner.cmp(&b.inner));
+        Ok(paths)
+    }
+    
+    /// Keep only the paths whose extension matches `ext` (case-insensitive, leading dot optional)
+    pub fn filter_by_extension<I>(paths: I, ext: &str) -> Vec<ValidatedPath>
+    where
+        I: IntoIterator<Item = ValidatedPath>,
+    {
+        let ext = ext.trim_start_matches('.');
+        paths
+            .into_iter()
+            .filter(|path| {
+                path.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| e.eq_ignore_ascii_case(ext))
+            })
+            .collect()
+    }
+    
+    /// Iterate over the named components of the path
+    ///
+    /// Only normal components are yielded: the root (`/`), Windows prefixes
+    /// (`C:`), `.` and `..` are skipped. No normalization is applied, so
+    /// `a/../b` yields `a` then `b`.
+    pub fn components(&self) -> impl Iterator<Item = &OsStr> {
+        self.inner.components().filter_map(|component| match component {
+            Component::Normal(name) => Some(name),
+            _ => None,
+        })
+    }
+    
+    /// Count the named components after lexical normalization
+    ///
+    /// `.` and `..` are resolved first, so `a/b/../c` has depth 2; leading `..`
+    /// components of a relative path are not counted. The root and prefix do
+    /// not count either, so `/` and `.` both have depth 0.
+    pub fn depth(&self) -> usize {
+        normalize_lexically(&self.inner)
+            .components()
+            .filter(|component| matches!(component, Component::Normal(_)))
+            .count()
+    }
+    
     /// Convert to string representation
     pub fn to_string_lossy(&self) -> std::borrow::Cow<'_, str> {
         self.inner.to_string_lossy()
@@ -92,4 +255,41 @@ impl ValidatedPath {
             )).into()),
         }
     }
-}
\ No newline at end of file
+}
+
+// === Conversion trait implementations ===
+impl AsRef<Path> for ValidatedPath {
+    fn as_ref(&self) -> &Path {
+        &self.inner
+    }
+}
+
+/// Collapse `.` and `..` components without touching the filesystem
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                // Cannot go above the root
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => normalized.push(Component::ParentDir.as_os_str()),
+            },
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    
+    non_empty_or_cur_dir(&normalized)
+}
+
+/// Map an empty path to `.` so the result still passes validation
+fn non_empty_or_cur_dir(path: &Path) -> PathBuf {
+    match path.as_os_str().is_empty() {
+        true => PathBuf::from(Component::CurDir.as_os_str()),
+        false => path.to_path_buf(),
+    }
+}
@@ -205,4 +205,188 @@ mod tests {
         let pathbuf_path = PathBuf::from("/valid/path");
         assert!(pathbuf_path.parse_path().is_ok());
     }
+
+    #[test]
+    fn test_validated_path_normalize() {
+        let path = ValidatedPath::new("/data/./raw/../clean/file.dbc").unwrap();
+        assert_eq!(path.normalize().as_path(), std::path::Path::new("/data/clean/file.dbc"));
+
+        // Leading parent components of relative paths are kept
+        let path = ValidatedPath::new("../../etc/passwd").unwrap();
+        assert_eq!(path.normalize().as_path(), std::path::Path::new("../../etc/passwd"));
+
+        // Cannot climb above the root
+        let path = ValidatedPath::new("/../etc").unwrap();
+        assert_eq!(path.normalize().as_path(), std::path::Path::new("/etc"));
+
+        // Fully collapsed relative path becomes "."
+        let path = ValidatedPath::new("a/..").unwrap();
+        assert_eq!(path.normalize().as_path(), std::path::Path::new("."));
+    }
+
+    #[test]
+    fn test_validated_path_is_within() {
+        let base = std::path::Path::new("/srv/data");
+
+        assert!(ValidatedPath::new("/srv/data/sub/file.dbf").unwrap().is_within(base));
+        assert!(ValidatedPath::new("/srv/data/sub/../file.dbf").unwrap().is_within(base));
+        assert!(!ValidatedPath::new("/srv/data/../../etc/passwd").unwrap().is_within(base));
+        assert!(!ValidatedPath::new("/srv/database").unwrap().is_within(base));
+        assert!(!ValidatedPath::new("relative/file").unwrap().is_within(base));
+    }
+
+    #[test]
+    fn test_validated_path_new_within() {
+        let path = ValidatedPath::new_within("/srv/data/./a/b", "/srv/data").unwrap();
+        assert_eq!(path.as_path(), std::path::Path::new("/srv/data/a/b"));
+
+        assert!(ValidatedPath::new_within("/srv/data/../../etc/passwd", "/srv/data").is_err());
+        assert!(ValidatedPath::new_within("../../etc/passwd", "data").is_err());
+        assert!(ValidatedPath::new_within("data/reports/../x.csv", "data").is_ok());
+    }
+
+    #[test]
+    fn test_validated_path_extension_and_stem() {
+        let path = ValidatedPath::new("/data/sus/RDSP2401.dbc").unwrap();
+        assert_eq!(path.file_stem().unwrap(), "RDSP2401");
+
+        let dbf = path.with_extension("dbf").unwrap();
+        assert_eq!(dbf.as_path(), std::path::Path::new("/data/sus/RDSP2401.dbf"));
+
+        let renamed = path.with_file_name("RDSP2402.dbc").unwrap();
+        assert_eq!(renamed.as_path(), std::path::Path::new("/data/sus/RDSP2402.dbc"));
+
+        // Invalid characters are rejected by re-validation
+        assert!(path.with_extension("db?").is_err());
+        assert!(path.with_file_name("bad|name.dbc").is_err());
+    }
+
+    #[test]
+    fn test_validated_path_read_dir_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        ["RDSP2401.dbc", "RDSP2402.DBC", "notes.txt", "PASP2401.dbf"]
+            .iter()
+            .for_each(|name| { std::fs::write(dir.path().join(name), b"").unwrap(); });
+
+        let root = ValidatedPath::new(dir.path()).unwrap();
+        let entries = root.read_dir_validated().unwrap();
+        assert_eq!(entries.len(), 4);
+
+        let dbc = ValidatedPath::filter_by_extension(entries.clone(), "dbc");
+        assert_eq!(dbc.len(), 2);
+        assert_eq!(dbc[0].file_name().unwrap(), "RDSP2401.dbc");
+
+        let dbf = ValidatedPath::filter_by_extension(entries, ".dbf");
+        assert_eq!(dbf.len(), 1);
+    }
+
+    #[test]
+    fn test_validated_path_read_dir_missing() {
+        use crate::core::SharedError;
+        use crate::utils::{UtilsError, PathError};
+
+        let missing = ValidatedPath::new("/definitely/not/a/real/dir").unwrap();
+        match missing.read_dir_validated() {
+            Err(SharedError::Utils(UtilsError::Path(PathError::Io(e)))) => {
+                assert_eq!(e.kind(), std::io::ErrorKind::NotFound);
+            }
+            other => panic!("expected PathError::Io, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_path_like_canonicalize_preserves_io_kind() {
+        use crate::core::SharedError;
+        use crate::utils::{UtilsError, PathError};
+
+        let missing = "/definitely/not/a/real/path";
+        match missing.canonicalize() {
+            Err(SharedError::Utils(UtilsError::Path(PathError::Io(e)))) => {
+                assert_eq!(e.kind(), std::io::ErrorKind::NotFound);
+            }
+            other => panic!("expected PathError::Io, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validated_path_components_and_depth() {
+        use std::ffi::OsStr;
+
+        let path = ValidatedPath::new("/data/sih/2024/file.dbc").unwrap();
+        let names: Vec<&OsStr> = path.components().collect();
+        assert_eq!(names, ["data", "sih", "2024", "file.dbc"].map(OsStr::new));
+        assert_eq!(path.depth(), 4);
+
+        // Root and `.` are skipped; `..` is skipped by components but resolved by depth
+        let path = ValidatedPath::new("./a/b/../c").unwrap();
+        let names: Vec<&OsStr> = path.components().collect();
+        assert_eq!(names, ["a", "b", "c"].map(OsStr::new));
+        assert_eq!(path.depth(), 2);
+
+        // Leading `..` on relative paths does not add depth
+        assert_eq!(ValidatedPath::new("../../x").unwrap().depth(), 1);
+        assert_eq!(ValidatedPath::new("/").unwrap().depth(), 0);
+        assert_eq!(ValidatedPath::new(".").unwrap().depth(), 0);
+        assert_eq!(ValidatedPath::new("/a/../..").unwrap().depth(), 0);
+    }
+
+    #[test]
+    fn test_validated_path_relative_to_and_strip_prefix() {
+        use std::path::Path;
+        use crate::core::SharedError;
+        use crate::utils::{UtilsError, PathError};
+
+        let path = ValidatedPath::new("/mirror/sih/2024/RDSP2401.dbc").unwrap();
+        let rel = path.relative_to(Path::new("/mirror")).unwrap();
+        assert_eq!(rel.as_path(), Path::new("sih/2024/RDSP2401.dbc"));
+        assert_eq!(path.strip_prefix(Path::new("/mirror/sih")).unwrap().as_path(), Path::new("2024/RDSP2401.dbc"));
+
+        // Rebuild the same subpath under another root
+        let mirrored = ValidatedPath::new("/local").unwrap().join(rel.as_path()).unwrap();
+        assert_eq!(mirrored.as_path(), Path::new("/local/sih/2024/RDSP2401.dbc"));
+
+        // relative_to normalizes both sides, strip_prefix does not
+        let messy = ValidatedPath::new("/mirror/./sih/../sia/file.dbc").unwrap();
+        assert_eq!(messy.relative_to(Path::new("/mirror/sia/")).unwrap().as_path(), Path::new("file.dbc"));
+        assert!(messy.strip_prefix(Path::new("/mirror/sia")).is_err());
+
+        // Equal paths give `.`
+        assert_eq!(path.relative_to(path.as_path()).unwrap().as_path(), Path::new("."));
+        assert_eq!(path.strip_prefix(path.as_path()).unwrap().as_path(), Path::new("."));
+
+        // Component-wise, not string-wise, prefixes
+        assert!(path.relative_to(Path::new("/mir")).is_err());
+        match path.relative_to(Path::new("/other")) {
+            Err(SharedError::Utils(UtilsError::Path(PathError::NotUnderBase(msg)))) => {
+                assert!(msg.contains("/other"));
+            }
+            other => panic!("expected PathError::NotUnderBase, got {:?}", other),
+        }
+        assert!(ValidatedPath::new("/mirror/../etc/passwd").unwrap().relative_to(Path::new("/mirror")).is_err());
+    }
+
+    #[test]
+    fn test_validated_path_join_validated() {
+        use std::path::Path;
+        use crate::core::SharedError;
+        use crate::utils::{UtilsError, PathError};
+
+        let base = ValidatedPath::new("/data/sih").unwrap();
+        assert_eq!(base.join_validated("2024/a.dbc").unwrap().as_path(), Path::new("/data/sih/2024/a.dbc"));
+
+        // The error names the component, not the joined path
+        match base.join_validated("raw*.dbc") {
+            Err(SharedError::Utils(UtilsError::Path(PathError::InvalidCharacters(msg)))) => {
+                assert!(msg.contains("raw*.dbc"));
+                assert!(!msg.contains("/data/sih"));
+            }
+            other => panic!("expected PathError::InvalidCharacters, got {:?}", other),
+        }
+        assert!(base.join_validated("").is_err());
+        assert!(base.join_validated("a\0b").is_err());
+
+        // ValidatedPath is AsRef<Path>, so validated paths join directly
+        let sub = ValidatedPath::new("2024").unwrap();
+        assert_eq!(base.join(&sub).unwrap(), base.join_validated(&sub).unwrap());
+    }
 }
\ No newline at end of file
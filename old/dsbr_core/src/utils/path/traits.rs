@@ -415,9 +415,7 @@ impl PathLike for PathBuf {
     
     fn canonicalize(&self) -> Result<PathBuf> {
         Path::canonicalize(self.as_path())
-            .map_err(|e| UtilsError::Path(PathError::cannot_convert_path(
-                format!("Cannot canonicalize path {}: {}", self.display(), e)
-            )).into())
+            .map_err(|e| UtilsError::Path(PathError::io(e)).into())
     }
 }
 
@@ -461,9 +459,7 @@ impl PathLike for &Path {
     
     fn canonicalize(&self) -> Result<PathBuf> {
         Path::canonicalize(*self)
-            .map_err(|e| UtilsError::Path(PathError::cannot_convert_path(
-                format!("Cannot canonicalize path {}: {}", self.display(), e)
-            )).into())
+            .map_err(|e| UtilsError::Path(PathError::io(e)).into())
     }
 }
 
@@ -501,9 +497,7 @@ impl PathLike for String {
     
     fn canonicalize(&self) -> Result<PathBuf> {
         Path::new(self).canonicalize()
-            .map_err(|e| UtilsError::Path(PathError::cannot_convert_path(
-                format!("Cannot canonicalize path {}: {}", self, e)
-            )).into())
+            .map_err(|e| UtilsError::Path(PathError::io(e)).into())
     }
 }
 
@@ -541,9 +535,7 @@ impl PathLike for &String {
     
     fn canonicalize(&self) -> Result<PathBuf> {
         Path::new(self).canonicalize()
-            .map_err(|e| UtilsError::Path(PathError::cannot_convert_path(
-                format!("Cannot canonicalize path {}: {}", self, e)
-            )).into())
+            .map_err(|e| UtilsError::Path(PathError::io(e)).into())
     }
 }
 
@@ -587,9 +579,7 @@ impl PathLike for OsStr {
     
     fn canonicalize(&self) -> Result<PathBuf> {
         Path::new(self).canonicalize()
-            .map_err(|e| UtilsError::Path(PathError::cannot_convert_path(
-                format!("Cannot canonicalize path {:?}: {}", self, e)
-            )).into())
+            .map_err(|e| UtilsError::Path(PathError::io(e)).into())
     }
 }
 
@@ -633,9 +623,7 @@ impl PathLike for &OsStr {
     
     fn canonicalize(&self) -> Result<PathBuf> {
         Path::new(self).canonicalize()
-            .map_err(|e| UtilsError::Path(PathError::cannot_convert_path(
-                format!("Cannot canonicalize path {:?}: {}", self, e)
-            )).into())
+            .map_err(|e| UtilsError::Path(PathError::io(e)).into())
     }
 }
 
@@ -679,9 +667,7 @@ impl PathLike for OsString {
     
     fn canonicalize(&self) -> Result<PathBuf> {
         Path::new(self).canonicalize()
-            .map_err(|e| UtilsError::Path(PathError::cannot_convert_path(
-                format!("Cannot canonicalize path {:?}: {}", self, e)
-            )).into())
+            .map_err(|e| UtilsError::Path(PathError::io(e)).into())
     }
 }
 
@@ -725,9 +711,7 @@ impl PathLike for &OsString {
     
     fn canonicalize(&self) -> Result<PathBuf> {
         Path::new(self).canonicalize()
-            .map_err(|e| UtilsError::Path(PathError::cannot_convert_path(
-                format!("Cannot canonicalize path {:?}: {}", self, e)
-            )).into())
+            .map_err(|e| UtilsError::Path(PathError::io(e)).into())
     }
 }
 
@@ -765,8 +749,6 @@ impl PathLike for &str {
     
     fn canonicalize(&self) -> Result<PathBuf> {
         Path::new(self).canonicalize()
-            .map_err(|e| UtilsError::Path(PathError::cannot_convert_path(
-                format!("Cannot canonicalize path {}: {}", self, e)
-            )).into())
+            .map_err(|e| UtilsError::Path(PathError::io(e)).into())
     }
 }
\ No newline at end of file
@@ -0,0 +1,82 @@
+//! Serde wire format shared by `Day`, `Month` and `Year`
+//!
+//! Each one serializes as its bare number (`5`, `1`, `2024`). The derived
+//! fields (texts, names, leap flags) are rebuilt on deserialization and are
+//! not part of the wire format. Self-describing formats such as JSON also
+//! accept a string ("05", "Janeiro", "23") or an object with the number under
+//! `day`/`month`/`year`; every form is validated through the type's `from`.
+//! Binary formats that don't describe their own types (bincode, postcard)
+//! only carry the bare number.
+
+use std::fmt;
+use std::marker::PhantomData;
+use serde::Deserializer;
+use serde::de::{self, IgnoredAny, MapAccess, Unexpected, Visitor};
+use crate::core::Result;
+
+/// A calendar component stored on the wire as a single number
+pub(crate) trait NumberedComponent: Sized {
+    /// Key holding the number in the object form
+    const FIELD: &'static str;
+
+    fn expecting(f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    fn from_wire_number(value: i64) -> Result<Self>;
+
+    fn from_wire_str(value: &str) -> Result<Self>;
+
+    /// Ask a binary deserializer for the number type `Serialize` writes
+    fn deserialize_number<'de, D, V>(deserializer: D, visitor: V) -> std::result::Result<V::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Visitor<'de>;
+}
+
+/// Deserialize a component from any of the forms above
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> std::result::Result<T, D::Error>
+where
+    T: NumberedComponent,
+    D: Deserializer<'de>,
+{
+    match deserializer.is_human_readable() {
+        true => deserializer.deserialize_any(ComponentVisitor(PhantomData)),
+        false => T::deserialize_number(deserializer, ComponentVisitor(PhantomData)),
+    }
+}
+
+struct ComponentVisitor<T>(PhantomData<T>);
+
+impl<'de, T: NumberedComponent> Visitor<'de> for ComponentVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::expecting(f)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> std::result::Result<T, E> {
+        T::from_wire_number(value).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> std::result::Result<T, E> {
+        match i64::try_from(value) {
+            Ok(value) => self.visit_i64(value),
+            Err(_) => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<T, E> {
+        T::from_wire_str(value).map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<T, A::Error> {
+        let mut number: Option<i64> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key == T::FIELD {
+                true => number = Some(map.next_value()?),
+                false => { map.next_value::<IgnoredAny>()?; }
+            }
+        }
+        let number = number.ok_or_else(|| de::Error::missing_field(T::FIELD))?;
+        T::from_wire_number(number).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,117 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use crate::utils::time::datetime::DateTime;
+
+/// A business calendar decides which days are holidays and which are working days
+///
+/// Implement this for a country, state or municipality and pass it to
+/// `DateTime::add_business_days_with_calendar`.
+pub trait Calendar {
+    /// Check if the date of `dt` is a holiday (time-of-day is ignored)
+    fn is_holiday(&self, dt: &DateTime) -> bool;
+
+    /// Check if the date of `dt` is a working day: not a weekend and not a holiday
+    fn is_business_day(&self, dt: &DateTime) -> bool {
+        !is_weekend(dt) && !self.is_holiday(dt)
+    }
+}
+
+/// Calendar with no holidays; only Saturdays and Sundays are non-business days
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WeekendsOnlyCalendar;
+
+impl Calendar for WeekendsOnlyCalendar {
+    fn is_holiday(&self, _dt: &DateTime) -> bool {
+        false
+    }
+}
+
+/// Brazilian national calendar, following the banking (ANBIMA) holiday list
+///
+/// Includes the fixed national holidays, Carnival Monday and Tuesday, Good
+/// Friday and Corpus Christi. Dia da Consciência Negra (Nov 20) is national
+/// from 2024 on. State and municipal holidays can be added with
+/// `with_local_holidays`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BrazilianCalendar {
+    local_holidays: Vec<(u8, u8)>,
+}
+
+impl BrazilianCalendar {
+    // Fixed-date national holidays as (month, day)
+    const FIXED_HOLIDAYS: [(u8, u8); 8] = [
+        (1, 1),   // Confraternização Universal
+        (4, 21),  // Tiradentes
+        (5, 1),   // Dia do Trabalho
+        (9, 7),   // Independência
+        (10, 12), // Nossa Senhora Aparecida
+        (11, 2),  // Finados
+        (11, 15), // Proclamação da República
+        (12, 25), // Natal
+    ];
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add recurring local (state or municipal) holidays as (month, day) pairs
+    pub fn with_local_holidays(mut self, holidays: impl IntoIterator<Item = (u8, u8)>) -> Self {
+        self.local_holidays.extend(holidays);
+        self
+    }
+
+    /// Check whether a calendar date is a holiday
+    fn is_holiday_date(&self, date: NaiveDate) -> bool {
+        let key = (date.month() as u8, date.day() as u8);
+        if Self::FIXED_HOLIDAYS.contains(&key) || self.local_holidays.contains(&key) {
+            return true;
+        }
+        if key == (11, 20) && date.year() >= 2024 {
+            return true;
+        }
+
+        match easter_sunday(date.year()) {
+            Some(easter) => {
+                let offset = (date - easter).num_days();
+                // Carnival Monday/Tuesday, Good Friday, Corpus Christi
+                matches!(offset, -48 | -47 | -2 | 60)
+            }
+            None => false,
+        }
+    }
+}
+
+impl Calendar for BrazilianCalendar {
+    fn is_holiday(&self, dt: &DateTime) -> bool {
+        match dt.to_chrono_naive() {
+            Ok(naive) => self.is_holiday_date(naive.date()),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Check if the date of `dt` falls on a Saturday or Sunday
+pub fn is_weekend(dt: &DateTime) -> bool {
+    match dt.to_chrono_naive() {
+        Ok(naive) => matches!(naive.weekday(), Weekday::Sat | Weekday::Sun),
+        Err(_) => false,
+    }
+}
+
+/// Western (Gregorian) Easter Sunday for a year, via the anonymous Gregorian algorithm
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
@@ -0,0 +1,75 @@
+/// Calendar-aware difference between two DateTimes
+///
+/// Unlike `Duration`, months and years are counted on the calendar, so
+/// Jan 31 to Mar 1 is "1 month, 1 day" rather than a fixed number of
+/// nanoseconds divided by an average month. Sub-second precision is dropped.
+/// Created with `DateTime::calendar_diff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CalendarDiff {
+    pub years: u32,
+    pub months: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    /// True when the `other` DateTime was after `self`
+    pub negative: bool,
+}
+
+impl CalendarDiff {
+    /// Check if every component is zero
+    pub fn is_zero(&self) -> bool {
+        self.components().iter().all(|&value| value == 0)
+    }
+
+    /// Format in English, largest unit first: "2 months, 3 days"
+    ///
+    /// Zero components are skipped and the sign is not shown, so callers can
+    /// append "ago" or prepend "in" themselves. An empty diff is "0 seconds".
+    pub fn to_readable_en(&self) -> String {
+        const UNITS: [(&str, &str); 6] = [
+            ("year", "years"), ("month", "months"), ("day", "days"),
+            ("hour", "hours"), ("minute", "minutes"), ("second", "seconds"),
+        ];
+        let parts = self.readable_parts(&UNITS);
+        match parts.is_empty() {
+            true => "0 seconds".to_string(),
+            false => parts.join(", "),
+        }
+    }
+
+    /// Format in Brazilian Portuguese, largest unit first: "2 meses e 3 dias"
+    ///
+    /// Zero components are skipped and the sign is not shown. An empty diff
+    /// is "0 segundos".
+    pub fn to_readable_ptbr(&self) -> String {
+        const UNITS: [(&str, &str); 6] = [
+            ("ano", "anos"), ("mês", "meses"), ("dia", "dias"),
+            ("hora", "horas"), ("minuto", "minutos"), ("segundo", "segundos"),
+        ];
+        let mut parts = self.readable_parts(&UNITS);
+        match parts.pop() {
+            None => "0 segundos".to_string(),
+            Some(last) if parts.is_empty() => last,
+            Some(last) => format!("{} e {}", parts.join(", "), last),
+        }
+    }
+
+    // === Helper methods ===
+
+    fn components(&self) -> [u32; 6] {
+        [self.years, self.months, self.days, self.hours, self.minutes, self.seconds]
+    }
+
+    fn readable_parts(&self, units: &[(&str, &str); 6]) -> Vec<String> {
+        self.components()
+            .iter()
+            .zip(units)
+            .filter(|(value, _)| **value > 0)
+            .map(|(value, (singular, plural))| match value {
+                1 => format!("1 {}", singular),
+                _ => format!("{} {}", value, plural),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+use crate::utils::time::datetime::DateTime;
+
+/// Display adapter that formats a borrowed DateTime as ISO8601
+///
+/// Honors the formatter's precision as the number of fractional-second
+/// digits (`{:.3}` gives milliseconds, `{:.0}` no fraction; values above 9
+/// are capped at 9). Without a precision it matches `DateTime::to_iso8601`.
+/// Created with `DateTime::iso8601`.
+#[derive(Debug, Clone, Copy)]
+pub struct Iso8601Display<'a> {
+    datetime: &'a DateTime,
+}
+
+impl<'a> Iso8601Display<'a> {
+    pub fn new(datetime: &'a DateTime) -> Self {
+        Self { datetime }
+    }
+}
+
+impl fmt::Display for Iso8601Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => self.datetime.write_iso8601_precision(f, precision.min(9)),
+            None => self.datetime.write_iso8601(f),
+        }
+    }
+}
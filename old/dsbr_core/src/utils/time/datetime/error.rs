@@ -1,4 +1,5 @@
 use thiserror::Error;
+use crate::utils::time::year::{MIN_YEAR, MAX_YEAR};
 
 #[derive(Error, Debug)]
 pub enum DateTimeError {
@@ -20,6 +21,10 @@ pub enum DateTimeError {
     #[error("Timestamp conversion error: {0}")]
     TimestampConversion(String),
     
+    /// `year` is `None` when the timestamp is too large to land on any date
+    #[error("Timestamp {timestamp} is out of range: {}", describe_out_of_range_year(.year))]
+    TimestampOutOfRange { timestamp: i64, year: Option<i32> },
+    
     #[error("Chrono conversion error: {0}")]
     ChronoConversion(String),
     
@@ -31,6 +36,30 @@ pub enum DateTimeError {
     
     #[error("Invalid timezone: {0}")]
     InvalidTimezone(String),
+    
+    #[error("Invalid interval: {0}")]
+    InvalidInterval(String),
+    
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+    
+    #[error("Cannot parse '{input}' as a datetime; tried: {}", describe_attempts(.attempts))]
+    UnparseableInput {
+        input: String,
+        /// (format name, reason it was rejected) in the order tried
+        attempts: Vec<(String, String)>,
+    },
+}
+
+fn describe_attempts(attempts: &[(String, String)]) -> String {
+    match attempts.is_empty() {
+        true => "no formats".to_string(),
+        false => attempts
+            .iter()
+            .map(|(format, reason)| format!("{} ({})", format, reason))
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
 }
 
 impl DateTimeError {
@@ -58,6 +87,10 @@ impl DateTimeError {
         Self::TimestampConversion(msg.into())
     }
     
+    pub fn timestamp_out_of_range(timestamp: i64, year: Option<i32>) -> Self {
+        Self::TimestampOutOfRange { timestamp, year }
+    }
+    
     pub fn chrono_conversion<S: Into<String>>(msg: S) -> Self {
         Self::ChronoConversion(msg.into())
     }
@@ -73,4 +106,35 @@ impl DateTimeError {
     pub fn invalid_timezone<S: Into<String>>(msg: S) -> Self {
         Self::InvalidTimezone(msg.into())
     }
-}
\ No newline at end of file
+    
+    pub fn invalid_interval<S: Into<String>>(msg: S) -> Self {
+        Self::InvalidInterval(msg.into())
+    }
+    
+    pub fn invalid_date<S: Into<String>>(msg: S) -> Self {
+        Self::InvalidDate(msg.into())
+    }
+    
+    /// Combine every format tried for one input into a single error
+    pub fn from_input_context<S, F, R>(input: S, attempted_formats: impl IntoIterator<Item = (F, R)>) -> Self
+    where
+        S: Into<String>,
+        F: Into<String>,
+        R: ToString,
+    {
+        Self::UnparseableInput {
+            input: input.into(),
+            attempts: attempted_formats
+                .into_iter()
+                .map(|(format, reason)| (format.into(), reason.to_string()))
+                .collect(),
+        }
+    }
+}
+
+fn describe_out_of_range_year(year: &Option<i32>) -> String {
+    match year {
+        Some(year) => format!("year {} is outside {}-{}", year, MIN_YEAR, MAX_YEAR),
+        None => format!("no date in {}-{}", MIN_YEAR, MAX_YEAR),
+    }
+}
@@ -212,4 +212,68 @@ mod integration_tests {
         assert_eq!(time_of_day.to_hms(), "14:30:45");
         assert_eq!(time_of_day.to_readable(), "14h 30m 45s");
     }
+
+    #[test]
+    fn test_builder_from_datetime() {
+        let dt = DateTime::new(
+            Year::from_number(2024).unwrap(),
+            Month::from_number(3).unwrap(),
+            Day::from_number(15).unwrap(),
+            14, 30, 45, 123_456_789,
+        ).unwrap();
+
+        // Unchanged round trip keeps every field, including nanoseconds
+        assert_eq!(DateTimeBuilder::from_datetime(&dt).build().unwrap(), dt);
+
+        let edited = dt.to_builder().hour(9).day(Day::from_number(1).unwrap()).build().unwrap();
+        assert_eq!(edited.hour, 9);
+        assert_eq!(edited.minute, 30);
+        assert_eq!(edited.second, 45);
+        assert_eq!(edited.nanosecond, 123_456_789);
+        assert_eq!(edited.day.day, 1);
+        assert_eq!(edited.month.month, 3);
+        assert_eq!(edited.year.year, 2024);
+    }
+
+    #[test]
+    fn test_builder_last_writer_wins() {
+        let base = || DateTime::builder().date(2024, 3, 15).unwrap();
+
+        // Field setters after a whole-time setter override it
+        let dt = base().at_noon().hour(9).build().unwrap();
+        assert_eq!((dt.hour, dt.minute, dt.second), (9, 0, 0));
+
+        // Whole-time setters reset every field they cover
+        let dt = base().hour(9).minute(45).at_midnight().build().unwrap();
+        assert_eq!((dt.hour, dt.minute, dt.second, dt.nanosecond), (0, 0, 0, 0));
+
+        let dt = base().at_midnight().at_noon().build().unwrap();
+        assert_eq!(dt.hour, 12);
+
+        // time() leaves the sub-second part alone
+        let dt = base().millisecond(250).time(8, 15, 30).build().unwrap();
+        assert_eq!((dt.hour, dt.minute, dt.second, dt.nanosecond), (8, 15, 30, 250_000_000));
+    }
+
+    #[test]
+    fn test_builder_build_strict() {
+        let base = || DateTime::builder().date(2024, 3, 15).unwrap();
+
+        assert!(base().at_noon().hour(9).build_strict().is_err());
+        assert!(base().at_midnight().at_noon().build_strict().is_err());
+        assert!(base().time(8, 0, 0).minute(30).build_strict().is_err());
+
+        // Setting a field to the value it already has is not a conflict
+        assert!(base().at_noon().hour(12).build_strict().is_ok());
+        assert!(base().at_midnight().at_midnight().build_strict().is_ok());
+
+        // Field setters before a whole-time setter, or without one, are fine
+        assert!(base().hour(9).minute(30).build_strict().is_ok());
+        assert!(base().hour(9).at_noon().build_strict().is_ok());
+        assert!(base().at_noon().millisecond(0).build_strict().is_ok());
+
+        // Editing an existing DateTime is not a conflict
+        let dt = base().time(10, 0, 0).build().unwrap();
+        assert_eq!(dt.to_builder().hour(11).build_strict().unwrap().hour, 11);
+    }
 }
\ No newline at end of file
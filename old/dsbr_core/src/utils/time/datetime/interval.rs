@@ -0,0 +1,93 @@
+use crate::core::Result;
+use crate::utils::{UtilsError};
+use crate::utils::time::Duration;
+use crate::utils::time::datetime::{DateTime, DateTimeError};
+
+/// DateTimeInterval is a closed range of time `[start, end]`
+///
+/// Both endpoints are inclusive, so intervals that only touch at an
+/// endpoint still overlap. `start <= end` is enforced at construction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateTimeInterval {
+    start: DateTime,
+    end: DateTime,
+}
+
+impl DateTimeInterval {
+    // === Constructors ===
+
+    /// Create an interval, rejecting `start > end`
+    pub fn new(start: DateTime, end: DateTime) -> Result<Self> {
+        match start <= end {
+            true => Ok(Self { start, end }),
+            false => Err(UtilsError::DateTime(
+                DateTimeError::invalid_interval(format!("Start {} is after end {}", start, end))
+            ).into()),
+        }
+    }
+
+    // === Accessors ===
+
+    pub fn start(&self) -> &DateTime {
+        &self.start
+    }
+
+    pub fn end(&self) -> &DateTime {
+        &self.end
+    }
+
+    /// Get the length of the interval
+    ///
+    /// Works for endpoints on either side of the Unix epoch; the whole
+    /// supported range (1900-2100) fits in a `Duration`.
+    pub fn duration(&self) -> Option<Duration> {
+        let start = self.start.signed_nanos_since_epoch()?;
+        let end = self.end.signed_nanos_since_epoch()?;
+        // `start <= end` is enforced at construction, so the difference is never negative
+        u64::try_from(end - start).ok().map(Duration::from_nanos)
+    }
+
+    // === Interval math ===
+
+    /// Check whether a DateTime falls inside the interval (inclusive)
+    pub fn contains(&self, dt: &DateTime) -> bool {
+        dt.is_between(&self.start, &self.end)
+    }
+
+    /// Check whether two intervals share at least one instant
+    pub fn overlaps(&self, other: &DateTimeInterval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Get the overlapping part of two intervals, if any
+    pub fn intersection(&self, other: &DateTimeInterval) -> Option<DateTimeInterval> {
+        match self.overlaps(other) {
+            true => Some(Self {
+                start: self.start.clone().max(&other.start),
+                end: self.end.clone().min(&other.end),
+            }),
+            false => None,
+        }
+    }
+
+    /// Merge two overlapping or touching intervals into one
+    ///
+    /// Returns `None` when there is a gap between them, since the result
+    /// would not be a single interval.
+    pub fn union(&self, other: &DateTimeInterval) -> Option<DateTimeInterval> {
+        match self.overlaps(other) {
+            true => Some(Self {
+                start: self.start.clone().min(&other.start),
+                end: self.end.clone().max(&other.end),
+            }),
+            false => None,
+        }
+    }
+}
+
+// === Display implementation ===
+impl std::fmt::Display for DateTimeInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.start, self.end)
+    }
+}
@@ -1,20 +1,33 @@
-use chrono::{NaiveDateTime, DateTime as ChronoDateTime, Utc, Datelike, Timelike};
-use crate::core::Result;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, DateTime as ChronoDateTime, Utc, Datelike, Timelike, Weekday};
+use crate::core::{Result, SharedError};
 use crate::utils::{UtilsError};
 use crate::utils::time::{Day, Month, Year, Duration};
+use crate::utils::time::year::{MIN_YEAR, MAX_YEAR};
 
+pub mod calendar;
+pub mod diff;
+pub mod display;
 pub mod error;
+pub mod interval;
+pub mod parser;
 pub mod traits;
+pub mod zoned;
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
 mod integration_tests;
 
+pub use calendar::{Calendar, BrazilianCalendar, WeekendsOnlyCalendar};
+pub use diff::CalendarDiff;
+pub use display::Iso8601Display;
 pub use error::DateTimeError;
+pub use interval::DateTimeInterval;
+pub use parser::DateTimeParser;
 pub use traits::{DateTimeValidatable, DateTimeFromInput};
+pub use zoned::ZonedDateTime;
 
 /// Format enumeration for ergonomic API
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub enum DateTimeFormat {
     ISO8601,           // 2024-03-15T14:30:45.123Z
@@ -25,9 +38,35 @@ pub enum DateTimeFormat {
     DDMMYYYY,          // 15032024
     MMDDYYYY,          // 03152024
     YYMM,              // 2403
+    YYYYMM,            // 202403
+    YYYY_MM,           // 2024-03
     Custom(String),    // Custom chrono pattern
 }
 
+/// Field order for slash-separated dates such as "01/02/2024"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DateOrder {
+    #[default]
+    DayFirst,          // DD/MM/YYYY (pt-BR)
+    MonthFirst,        // MM/DD/YYYY (US)
+}
+
+/// Field selector for `DateTime::extract`, mirroring SQL `date_part`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateField {
+    Year,              // 2024
+    Quarter,           // 1-4
+    Month,             // 1-12
+    Week,              // ISO 8601 week, 1-53
+    Day,               // 1-31
+    DayOfWeek,         // 0-6, Sunday is 0
+    DayOfYear,         // 1-366
+    Hour,              // 0-23
+    Minute,            // 0-59
+    Second,            // 0-59, whole seconds
+    Epoch,             // Unix timestamp in whole seconds
+}
+
 /// DateTime represents a specific moment in time with nanosecond precision
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DateTime {
@@ -83,11 +122,43 @@ impl DateTime {
         }
     }
     
+    /// Create a new DateTime and verify it falls on the `expected` weekday
+    ///
+    /// A data-quality gate for sources that encode both a date and its
+    /// weekday; a mismatch usually means a transcription error upstream.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_components_checked(
+        year: Year, month: Month, day: Day,
+        hour: u8, minute: u8, second: u8, nanosecond: u32,
+        expected: Weekday,
+    ) -> Result<Self> {
+        let dt = Self::new(year, month, day, hour, minute, second, nanosecond)?;
+        let actual = dt.to_chrono_naive()?.weekday();
+        match actual == expected {
+            true => Ok(dt),
+            false => Err(UtilsError::DateTime(
+                DateTimeError::invalid_date(format!(
+                    "{} is a {:?}, but the source says {:?}", dt.to_yyyy_mm_dd(), actual, expected
+                ))
+            ).into()),
+        }
+    }
+    
     /// Create from your existing types with time
     pub fn from_date_and_time(year: Year, month: Month, day: Day, hour: u8, minute: u8, second: u8) -> Result<Self> {
         Self::new(year, month, day, hour, minute, second, 0)
     }
     
+    /// Create from your existing types with time and millisecond precision
+    pub fn from_date_and_time_millis(year: Year, month: Month, day: Day, hour: u8, minute: u8, second: u8, millis: u32) -> Result<Self> {
+        match millis {
+            0..=999 => Self::new(year, month, day, hour, minute, second, millis * 1_000_000),
+            _ => Err(UtilsError::DateTime(
+                DateTimeError::invalid_time_component(format!("Millisecond must be 0-999, got {}", millis))
+            ).into()),
+        }
+    }
+    
     /// Create from your existing types at start of day
     pub fn from_date_start_of_day(year: Year, month: Month, day: Day) -> Result<Self> {
         Self::new(year, month, day, 0, 0, 0, 0)
@@ -101,24 +172,104 @@ impl DateTime {
         input.parse_datetime()
     }
     
+    // === Range bounds ===
+    
+    /// Earliest representable DateTime: the first instant of `MIN_YEAR`
+    ///
+    /// Built from the supported `Year` range, so it moves with `MIN_YEAR`.
+    /// Handy as the starting value when searching for the latest timestamp.
+    pub fn min_value() -> Self {
+        Self {
+            year: Year::all_years()[0],
+            month: Month::all_months()[0],
+            day: Day::all_days()[0],
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+        }
+    }
+    
+    /// Latest representable DateTime: the last nanosecond of `MAX_YEAR`
+    ///
+    /// Built from the supported `Year` range, so it moves with `MAX_YEAR`.
+    /// Handy as the starting value when searching for the earliest timestamp.
+    pub fn max_value() -> Self {
+        let years = Year::all_years();
+        Self {
+            year: years[years.len() - 1],
+            month: Month::all_months()[11],
+            day: Day::all_days()[30],
+            hour: 23,
+            minute: 59,
+            second: 59,
+            nanosecond: 999_999_999,
+        }
+    }
+    
+    /// Clamp into `min_value()..=max_value()`
+    ///
+    /// Construction already enforces the supported range, so this only changes
+    /// values built by hand through the public fields.
+    pub fn clamp_to_bounds(&self) -> DateTime {
+        self.clone().clamp(&Self::min_value(), &Self::max_value())
+    }
+    
     // === UTC and Timestamp constructors ===
     
     /// Create DateTime from UTC timestamp (seconds since Unix epoch)
+    ///
+    /// Fails with `TimestampOutOfRange` when the timestamp lands outside the
+    /// supported years (1900-2100), including values chrono cannot represent.
     pub fn from_timestamp(timestamp: i64) -> Result<Self> {
         let chrono_dt = ChronoDateTime::from_timestamp(timestamp, 0)
-            .ok_or_else(|| UtilsError::DateTime(
-                DateTimeError::timestamp_conversion(format!("Invalid timestamp: {}", timestamp))
-            ))?;
-        Self::from_chrono_utc(&chrono_dt)
+            .ok_or_else(|| UtilsError::DateTime(DateTimeError::timestamp_out_of_range(timestamp, None)))?;
+        Self::from_checked_timestamp(timestamp, &chrono_dt)
     }
     
     /// Create DateTime from UTC timestamp with nanoseconds
+    ///
+    /// `nanos` of one second or more carries into the seconds, so
+    /// `(ts, 1_500_000_000)` is `ts + 1.5s` rather than a leap second. Fails
+    /// with `TimestampOutOfRange` when the timestamp lands outside the
+    /// supported years (1900-2100), including values chrono cannot represent.
     pub fn from_timestamp_nanos(timestamp: i64, nanos: u32) -> Result<Self> {
+        let (timestamp, nanos) = timestamp.checked_add((nanos / 1_000_000_000) as i64)
+            .map(|secs| (secs, nanos % 1_000_000_000))
+            .ok_or_else(|| UtilsError::DateTime(DateTimeError::timestamp_out_of_range(timestamp, None)))?;
         let chrono_dt = ChronoDateTime::from_timestamp(timestamp, nanos)
-            .ok_or_else(|| UtilsError::DateTime(
-                DateTimeError::timestamp_conversion(format!("Invalid timestamp: {} with {} nanos", timestamp, nanos))
-            ))?;
-        Self::from_chrono_utc(&chrono_dt)
+            .ok_or_else(|| UtilsError::DateTime(DateTimeError::timestamp_out_of_range(timestamp, None)))?;
+        Self::from_checked_timestamp(timestamp, &chrono_dt)
+    }
+    
+    /// Reject timestamps outside the supported years before building the DateTime
+    fn from_checked_timestamp(timestamp: i64, chrono_dt: &ChronoDateTime<Utc>) -> Result<Self> {
+        match (MIN_YEAR..=MAX_YEAR).contains(&chrono_dt.year()) {
+            true => Self::from_chrono_utc(chrono_dt),
+            false => Err(UtilsError::DateTime(
+                DateTimeError::timestamp_out_of_range(timestamp, Some(chrono_dt.year()))
+            ).into()),
+        }
+    }
+    
+    /// Create DateTime from a Unix timestamp in milliseconds (JavaScript/JSON style)
+    ///
+    /// Pre-epoch values use floor division, so -1 maps to 1969-12-31T23:59:59.999.
+    /// Out-of-range errors report the timestamp in whole seconds.
+    pub fn from_unix_millis(millis: i64) -> Result<Self> {
+        let secs = millis.div_euclid(1_000);
+        let nanos = millis.rem_euclid(1_000) as u32 * 1_000_000;
+        Self::from_timestamp_nanos(secs, nanos)
+    }
+    
+    /// Create DateTime from a Unix timestamp in microseconds
+    ///
+    /// Pre-epoch values use floor division, so -1 maps to 1969-12-31T23:59:59.999999.
+    /// Out-of-range errors report the timestamp in whole seconds.
+    pub fn from_unix_micros(micros: i64) -> Result<Self> {
+        let secs = micros.div_euclid(1_000_000);
+        let nanos = micros.rem_euclid(1_000_000) as u32 * 1_000;
+        Self::from_timestamp_nanos(secs, nanos)
     }
     
     /// Create DateTime from current UTC time
@@ -127,6 +278,16 @@ impl DateTime {
         Self::from_chrono_utc(&now)
     }
     
+    /// Create DateTime from the machine's current local time
+    ///
+    /// The result is naive: its components are the local wall-clock time but
+    /// no offset is stored, so it should not be mixed with `now_utc` values.
+    /// Use `ZonedDateTime` when the offset needs to travel with the value.
+    pub fn now_local() -> Result<Self> {
+        let now = chrono::Local::now();
+        Self::from_chrono_naive(&now.naive_local())
+    }
+    
     /// Create DateTime from chrono UTC DateTime
     pub fn from_chrono_utc(dt: &ChronoDateTime<Utc>) -> Result<Self> {
         let naive = dt.naive_utc();
@@ -148,6 +309,34 @@ impl DateTime {
         )
     }
     
+    /// Parse ISO8601 like `from_iso8601`, but also accept a leap second (`23:59:60`)
+    ///
+    /// A leap second is stored the way chrono represents it: `second` stays 59
+    /// and `nanosecond` is pushed past 999_999_999 (1_000_000_000 + fraction).
+    /// `to_iso8601` writes it back as `:60`, and `to_chrono_naive` yields
+    /// chrono's leap-second value, so the instant round-trips through both.
+    /// Methods that rebuild a DateTime through `new` (arithmetic, `with_*`)
+    /// reject a stored leap second.
+    pub fn from_iso8601_allow_leap_second(input: &str) -> Result<Self> {
+        let cleaned = input.trim_end_matches('Z');
+        let parsed = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S"]
+            .iter()
+            .find_map(|pattern| NaiveDateTime::parse_from_str(cleaned, pattern).ok());
+        
+        match parsed {
+            Some(naive) if naive.nanosecond() >= 1_000_000_000 => {
+                let base = naive.with_nanosecond(naive.nanosecond() - 1_000_000_000)
+                    .ok_or_else(|| UtilsError::DateTime(
+                        DateTimeError::chrono_conversion(format!("Invalid leap second in '{}'", input))
+                    ))?;
+                let mut dt = Self::from_chrono_naive(&base)?;
+                dt.nanosecond += 1_000_000_000;
+                Ok(dt)
+            }
+            _ => Self::from_iso8601(input),
+        }
+    }
+    
     // === Format-based parsing ===
     
     /// Parse from various date formats
@@ -161,10 +350,134 @@ impl DateTime {
             DateTimeFormat::DDMMYYYY => Self::from_ddmmyyyy(input),
             DateTimeFormat::MMDDYYYY => Self::from_mmddyyyy(input),
             DateTimeFormat::YYMM => Self::from_yymm(input),
+            DateTimeFormat::YYYYMM => Self::from_yyyymm(input),
+            DateTimeFormat::YYYY_MM => Self::from_yyyy_mm(input),
             DateTimeFormat::Custom(pattern) => Self::from_custom_format(input, &pattern),
         }
     }
     
+    /// Parse a whole column of strings with one format in a single pass
+    ///
+    /// Returns one slot per input (`None` where parsing failed) together with
+    /// the failures as `(row index, error)` pairs, so ETL code can report bad
+    /// rows without stopping at the first one.
+    pub fn parse_column<'a>(
+        inputs: impl IntoIterator<Item = &'a str>,
+        format: DateTimeFormat,
+    ) -> (Vec<Option<DateTime>>, Vec<(usize, SharedError)>) {
+        Self::split_column_results(
+            inputs.into_iter().map(|input| Self::from_format(input, format.clone()))
+        )
+    }
+    
+    /// Parallel version of `parse_column`, splitting the rows across the rayon pool
+    ///
+    /// Results are merged back in input order, so the output matches
+    /// `parse_column` exactly. Lookups of `Year`/`Month`/`Day` go through
+    /// immutable statics and are safe to share between threads.
+    #[cfg(feature = "rayon")]
+    pub fn parse_column_parallel<S: AsRef<str> + Sync>(
+        inputs: &[S],
+        format: DateTimeFormat,
+    ) -> (Vec<Option<DateTime>>, Vec<(usize, SharedError)>) {
+        use rayon::prelude::*;
+        
+        let parsed: Vec<Result<DateTime>> = inputs
+            .par_iter()
+            .map(|input| Self::from_format(input.as_ref(), format.clone()))
+            .collect();
+        
+        Self::split_column_results(parsed)
+    }
+    
+    /// Split per-row results into value slots and `(row index, error)` pairs
+    fn split_column_results(
+        results: impl IntoIterator<Item = Result<DateTime>>,
+    ) -> (Vec<Option<DateTime>>, Vec<(usize, SharedError)>) {
+        let results = results.into_iter();
+        let mut values = Vec::with_capacity(results.size_hint().0);
+        let mut errors = Vec::new();
+        
+        for (index, result) in results.enumerate() {
+            match result {
+                Ok(dt) => values.push(Some(dt)),
+                Err(e) => {
+                    values.push(None);
+                    errors.push((index, e));
+                }
+            }
+        }
+        
+        (values, errors)
+    }
+    
+    /// Parse by trying every known format in priority order
+    ///
+    /// Formats are attempted as ISO8601, YYYY-MM-DD, DD/MM/YYYY, MM/DD/YYYY,
+    /// YYYYMMDD, DDMMYYYY, MMDDYYYY, YYMM, YYYYMM and finally YYYY-MM; the
+    /// first success wins.
+    ///
+    /// Ambiguous inputs such as "01/02/2024" resolve as DD/MM/YYYY (1 February)
+    /// since the crate is pt-BR oriented. MM/DD/YYYY is only used when the
+    /// DD/MM/YYYY reading is invalid, e.g. "03/15/2024". The same applies to
+    /// the compact DDMMYYYY/MMDDYYYY pair.
+    ///
+    /// On failure a single `DateTimeError::UnparseableInput` lists the input
+    /// and every format tried with the reason it was rejected.
+    pub fn parse_any(input: &str) -> Result<Self> {
+        let attempts: [(&str, fn(&str) -> Result<Self>); 10] = [
+            ("ISO8601", Self::from_iso8601),
+            ("YYYY-MM-DD", Self::from_yyyy_mm_dd),
+            ("DD/MM/YYYY", Self::from_dd_mm_yyyy),
+            ("MM/DD/YYYY", Self::from_mm_dd_yyyy),
+            ("YYYYMMDD", Self::from_yyyymmdd),
+            ("DDMMYYYY", Self::from_ddmmyyyy),
+            ("MMDDYYYY", Self::from_mmddyyyy),
+            ("YYMM", Self::from_yymm),
+            ("YYYYMM", Self::from_yyyymm),
+            ("YYYY-MM", Self::from_yyyy_mm),
+        ];
+
+        let mut failures = Vec::with_capacity(attempts.len());
+        for (name, parse) in attempts.iter() {
+            match parse(input) {
+                Ok(dt) => return Ok(dt),
+                Err(e) => failures.push((*name, Self::error_reason(&e))),
+            }
+        }
+
+        Err(UtilsError::DateTime(DateTimeError::from_input_context(input, failures)).into())
+    }
+    
+    /// The innermost message of a sub-parser error, without the "Utils error: Year error:" wrapping
+    fn error_reason(error: &SharedError) -> String {
+        match error {
+            SharedError::Utils(UtilsError::Day(e)) => e.to_string(),
+            SharedError::Utils(UtilsError::Month(e)) => e.to_string(),
+            SharedError::Utils(UtilsError::Year(e)) => e.to_string(),
+            SharedError::Utils(UtilsError::DateTime(e)) => e.to_string(),
+            other => other.to_string(),
+        }
+    }
+
+    /// Parse RFC 2822: "Fri, 15 Mar 2024 14:30:45 +0000" (converted to UTC)
+    pub fn from_rfc2822(input: &str) -> Result<Self> {
+        let chrono_dt = ChronoDateTime::parse_from_rfc2822(input)
+            .map_err(|e| UtilsError::DateTime(
+                DateTimeError::cannot_parse_datetime(format!("Invalid RFC2822 datetime '{}': {}", input, e))
+            ))?;
+        Self::from_chrono_utc(&chrono_dt.with_timezone(&Utc))
+    }
+    
+    /// Parse RFC 3339: "2024-03-15T14:30:45Z" or "2024-03-15T11:30:45-03:00" (converted to UTC)
+    pub fn from_rfc3339(input: &str) -> Result<Self> {
+        let chrono_dt = ChronoDateTime::parse_from_rfc3339(input)
+            .map_err(|e| UtilsError::DateTime(
+                DateTimeError::cannot_parse_datetime(format!("Invalid RFC3339 datetime '{}': {}", input, e))
+            ))?;
+        Self::from_chrono_utc(&chrono_dt.with_timezone(&Utc))
+    }
+    
     /// Parse ISO8601 format: "2024-03-15T14:30:45.123Z" or "2024-03-15T14:30:45"
     pub fn from_iso8601(input: &str) -> Result<Self> {
         let cleaned = input.trim_end_matches('Z');
@@ -198,10 +511,12 @@ impl DateTime {
     }
     
     /// Parse YYYYMMDD format: "20240315" (assumes start of day)
+    ///
+    /// The year must fall within the supported 1900-2100 range.
     pub fn from_yyyymmdd(input: &str) -> Result<Self> {
-        if input.len() != 8 {
+        if input.len() != 8 || !input.bytes().all(|b| b.is_ascii_digit()) {
             return Err(UtilsError::DateTime(
-                DateTimeError::invalid_format("YYYYMMDD format must be exactly 8 digits")
+                DateTimeError::invalid_format(format!("YYYYMMDD format must be exactly 8 ASCII digits, got '{}'", input))
             ).into());
         }
         
@@ -209,13 +524,55 @@ impl DateTime {
         let month_str = &input[4..6];
         let day_str = &input[6..8];
         
-        let year = Year::from(year_str)?;
+        let year_num = year_str.parse::<i32>()
+            .map_err(|_| UtilsError::DateTime(
+                DateTimeError::invalid_format(format!("Invalid year in YYYYMMDD format: {}", year_str))
+            ))?;
+        if !Year::is_valid(year_num) {
+            return Err(UtilsError::DateTime(
+                DateTimeError::invalid_format(format!(
+                    "YYYYMMDD year {} is outside the supported range {}-{}", year_num, MIN_YEAR, MAX_YEAR
+                ))
+            ).into());
+        }
+        
+        let year = Year::from_number(year_num)?;
         let month = Month::from(month_str)?;
         let day = Day::from(day_str)?;
         
         Self::new(year, month, day, 0, 0, 0, 0)
     }
     
+    /// Parse a fixed-width DATASUS/DBF date field
+    ///
+    /// Fields that are empty, all blanks/NULs or all zeros are missing data and
+    /// yield `None`. Eight digits parse as YYYYMMDD; six digits (`YYYYMM`,
+    /// possibly space-padded) parse as the first day of that month.
+    pub fn from_sus_date(field: &[u8]) -> Result<Option<Self>> {
+        if field.iter().all(|&b| matches!(b, b' ' | b'0' | 0)) {
+            return Ok(None);
+        }
+        
+        // DBF pads short values with trailing blanks or NULs
+        let end = field.iter().rposition(|&b| b != b' ' && b != 0).map_or(0, |last| last + 1);
+        let trimmed = &field[..end];
+        
+        let text = std::str::from_utf8(trimmed)
+            .ok()
+            .filter(|t| t.bytes().all(|b| b.is_ascii_digit()))
+            .ok_or_else(|| UtilsError::DateTime(
+                DateTimeError::invalid_format(format!("SUS date field must be ASCII digits, got {:?}", field))
+            ))?;
+        
+        match text.len() {
+            8 => Self::from_yyyymmdd(text).map(Some),
+            6 => Self::from_yyyymm(text).map(Some),
+            _ => Err(UtilsError::DateTime(
+                DateTimeError::invalid_format(format!("SUS date field must be YYYYMMDD or YYYYMM, got '{}'", text))
+            ).into()),
+        }
+    }
+    
     /// Parse YYYY-MM-DD format: "2024-03-15" (assumes start of day)
     pub fn from_yyyy_mm_dd(input: &str) -> Result<Self> {
         let mut parts = input.split('-');
@@ -234,6 +591,49 @@ impl DateTime {
         Self::new(year, month, day, 0, 0, 0, 0)
     }
     
+    /// Parse YYYY-MM-DD strictly: exactly "2024-03-05", zero-padded (assumes start of day)
+    ///
+    /// Unlike `from_yyyy_mm_dd`, every failure (shape, year range, month or
+    /// day out of range) is reported as a single `DateTimeError::InvalidDate`.
+    pub fn from_yyyy_mm_dd_strict(input: &str) -> Result<Self> {
+        let invalid = |reason: String| -> SharedError {
+            UtilsError::DateTime(DateTimeError::invalid_date(format!("'{}': {}", input, reason))).into()
+        };
+        
+        let bytes = input.as_bytes();
+        let well_formed = bytes.len() == 10
+            && bytes[4] == b'-'
+            && bytes[7] == b'-'
+            && bytes.iter().enumerate().all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit());
+        if !well_formed {
+            return Err(invalid("expected YYYY-MM-DD with a 4-digit year and 2-digit month and day".to_string()));
+        }
+        
+        // Slices are ASCII digits, so these parses cannot fail
+        let year_num: i32 = input[0..4].parse().map_err(|_| invalid("invalid year".to_string()))?;
+        let month_num: u8 = input[5..7].parse().map_err(|_| invalid("invalid month".to_string()))?;
+        let day_num: u8 = input[8..10].parse().map_err(|_| invalid("invalid day".to_string()))?;
+        
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year_num) {
+            return Err(invalid(format!("year {} is outside {}-{}", year_num, MIN_YEAR, MAX_YEAR)));
+        }
+        if !(1..=12).contains(&month_num) {
+            return Err(invalid(format!("month {} must be between 01 and 12", month_num)));
+        }
+        
+        let year = Year::from_number(year_num)?;
+        let month = Month::from_number(month_num)?;
+        let days_in_month = year.days_in_month(&month);
+        if day_num == 0 || day_num > days_in_month {
+            return Err(invalid(format!(
+                "day {} must be between 01 and {} for {} {}",
+                day_num, days_in_month, month.to_en(), year_num
+            )));
+        }
+        
+        Self::new(year, month, Day::from_number(day_num)?, 0, 0, 0, 0)
+    }
+    
     /// Parse DD/MM/YYYY format: "15/03/2024" (assumes start of day)
     pub fn from_dd_mm_yyyy(input: &str) -> Result<Self> {
         let mut parts = input.split('/');
@@ -270,6 +670,26 @@ impl DateTime {
         Self::new(year, month, day, 0, 0, 0, 0)
     }
     
+    /// Parse a slash-separated date with an explicit field order (assumes start of day)
+    pub fn parse_slash_date(input: &str, order: DateOrder) -> Result<Self> {
+        match order {
+            DateOrder::DayFirst => Self::from_dd_mm_yyyy(input),
+            DateOrder::MonthFirst => Self::from_mm_dd_yyyy(input),
+        }
+    }
+    
+    /// Check if a slash-separated date reads as two different valid dates
+    ///
+    /// True for "01/02/2024" (1 Feb or 2 Jan); false for "15/03/2024" (only
+    /// one reading is valid), for "05/05/2024" (both readings are the same
+    /// date) and for anything that is not a valid slash date at all.
+    pub fn is_ambiguous_slash_date(input: &str) -> bool {
+        match (Self::from_dd_mm_yyyy(input), Self::from_mm_dd_yyyy(input)) {
+            (Ok(day_first), Ok(month_first)) => day_first != month_first,
+            _ => false,
+        }
+    }
+    
     /// Parse DDMMYYYY format: "15032024" (assumes start of day)
     pub fn from_ddmmyyyy(input: &str) -> Result<Self> {
         if input.len() != 8 {
@@ -335,6 +755,59 @@ impl DateTime {
         Self::new(year, month, day, 0, 0, 0, 0)
     }
     
+    /// Parse YYYYMM format: "202403", e.g. a DATASUS competência (assumes first day of month, start of day)
+    ///
+    /// The year must fall within the supported 1900-2100 range.
+    pub fn from_yyyymm(input: &str) -> Result<Self> {
+        if input.len() != 6 || !input.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(UtilsError::DateTime(
+                DateTimeError::invalid_format(format!("YYYYMM format must be exactly 6 ASCII digits, got '{}'", input))
+            ).into());
+        }
+        
+        Self::from_year_and_month_text(&input[0..4], &input[4..6], "YYYYMM")
+    }
+    
+    /// Parse YYYY-MM format: "2024-03" (assumes first day of month, start of day)
+    ///
+    /// Both parts must be zero-padded digits and the year must fall within
+    /// the supported 1900-2100 range.
+    pub fn from_yyyy_mm(input: &str) -> Result<Self> {
+        match input.split_once('-') {
+            Some((year_str, month_str))
+                if year_str.len() == 4
+                    && month_str.len() == 2
+                    && year_str.bytes().chain(month_str.bytes()).all(|b| b.is_ascii_digit()) =>
+            {
+                Self::from_year_and_month_text(year_str, month_str, "YYYY-MM")
+            }
+            _ => Err(UtilsError::DateTime(
+                DateTimeError::invalid_format(format!("YYYY-MM format must look like '2024-03', got '{}'", input))
+            ).into()),
+        }
+    }
+    
+    /// Build the first day of a month from already shape-checked 4-digit year and 2-digit month text
+    fn from_year_and_month_text(year_str: &str, month_str: &str, format_name: &str) -> Result<Self> {
+        let year_num = year_str.parse::<i32>()
+            .map_err(|_| UtilsError::DateTime(
+                DateTimeError::invalid_format(format!("Invalid year in {} format: {}", format_name, year_str))
+            ))?;
+        if !Year::is_valid(year_num) {
+            return Err(UtilsError::DateTime(
+                DateTimeError::invalid_format(format!(
+                    "{} year {} is outside the supported range {}-{}", format_name, year_num, MIN_YEAR, MAX_YEAR
+                ))
+            ).into());
+        }
+        
+        let year = Year::from_number(year_num)?;
+        let month = Month::from(month_str)?;
+        let day = Day::from_number(1)?;
+        
+        Self::new(year, month, day, 0, 0, 0, 0)
+    }
+    
     /// Parse custom format using chrono patterns
     pub fn from_custom_format(input: &str, pattern: &str) -> Result<Self> {
         let naive = NaiveDateTime::parse_from_str(input, pattern)
@@ -353,7 +826,76 @@ impl DateTime {
     pub fn minute(&self) -> u8 { self.minute }
     pub fn second(&self) -> u8 { self.second }
     pub fn nanosecond(&self) -> u32 { self.nanosecond }
-    
+    /// Milliseconds into the current second (0-999, also during a leap second)
+    pub fn millisecond(&self) -> u32 { (self.nanosecond % 1_000_000_000) / 1_000_000 }
+    /// True when this DateTime holds a leap second (`23:59:60`)
+    pub fn is_leap_second(&self) -> bool { self.nanosecond >= 1_000_000_000 }
+    /// Microseconds into the current second (0-999_999, also during a leap second)
+    pub fn microsecond(&self) -> u32 { (self.nanosecond % 1_000_000_000) / 1_000 }
+
+    // === Field replacement ===
+
+    /// Return a copy with the year replaced (fails if the day is invalid for the new year)
+    pub fn with_year(&self, year: Year) -> Result<Self> {
+        Self::new(year, self.month, self.day, self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Return a copy with the month replaced (fails if the day is invalid for the new month)
+    pub fn with_month(&self, month: Month) -> Result<Self> {
+        Self::new(self.year, month, self.day, self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Return a copy with the day replaced (fails if the day is invalid for the month)
+    pub fn with_day(&self, day: Day) -> Result<Self> {
+        Self::new(self.year, self.month, day, self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Return a copy with the hour replaced
+    pub fn with_hour(&self, hour: u8) -> Result<Self> {
+        Self::new(self.year, self.month, self.day, hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Return a copy with the minute replaced
+    pub fn with_minute(&self, minute: u8) -> Result<Self> {
+        Self::new(self.year, self.month, self.day, self.hour, minute, self.second, self.nanosecond)
+    }
+
+    /// Return a copy with the second replaced
+    pub fn with_second(&self, second: u8) -> Result<Self> {
+        Self::new(self.year, self.month, self.day, self.hour, self.minute, second, self.nanosecond)
+    }
+
+    /// Return a copy with the nanosecond replaced
+    pub fn with_nanosecond(&self, nanosecond: u32) -> Result<Self> {
+        Self::new(self.year, self.month, self.day, self.hour, self.minute, self.second, nanosecond)
+    }
+
+    /// Replace the time of day from a duration since midnight (inverse of `time_since_midnight`)
+    ///
+    /// Fails when the duration is 24 hours or more.
+    pub fn with_time_of_day(&self, duration: &Duration) -> Result<Self> {
+        match duration.total_days() {
+            0 => Self::new(
+                self.year, self.month, self.day,
+                duration.hours() as u8, duration.minutes() as u8, duration.seconds() as u8,
+                (duration.total_nanos() % 1_000_000_000) as u32,
+            ),
+            _ => Err(UtilsError::DateTime(
+                DateTimeError::invalid_time_component(format!("Time of day must be under 24 hours, got {}", duration))
+            ).into()),
+        }
+    }
+
+    /// Same date at 00:00:00
+    pub fn set_midnight(&self) -> Result<Self> {
+        Self::new(self.year, self.month, self.day, 0, 0, 0, 0)
+    }
+
+    /// Same date at 12:00:00
+    pub fn set_noon(&self) -> Result<Self> {
+        Self::new(self.year, self.month, self.day, 12, 0, 0, 0)
+    }
+
     // === Duration arithmetic ===
     
     pub fn add_duration(&self, duration: &Duration) -> Result<Self> {
@@ -448,6 +990,32 @@ impl DateTime {
         }
     }
     
+    // === Saturating arithmetic ===
+    
+    /// Add a duration, clamping to `max_value()` instead of failing on overflow
+    pub fn add_duration_saturating(&self, duration: &Duration) -> DateTime {
+        let fits = match (self.signed_nanos_since_epoch(), Self::max_value().signed_nanos_since_epoch()) {
+            (Some(current), Some(max)) => duration.total_nanos() as i128 <= max - current,
+            _ => false,
+        };
+        match fits {
+            true => self.add_duration(duration).unwrap_or_else(|_| Self::max_value()),
+            false => Self::max_value(),
+        }
+    }
+    
+    /// Subtract a duration, clamping to `min_value()` instead of failing on underflow
+    pub fn subtract_duration_saturating(&self, duration: &Duration) -> DateTime {
+        let fits = match (self.signed_nanos_since_epoch(), Self::min_value().signed_nanos_since_epoch()) {
+            (Some(current), Some(min)) => duration.total_nanos() as i128 <= current - min,
+            _ => false,
+        };
+        match fits {
+            true => self.subtract_duration(duration).unwrap_or_else(|_| Self::min_value()),
+            false => Self::min_value(),
+        }
+    }
+    
     // === Convenient arithmetic methods ===
     
     pub fn add_hours(&self, hours: u64) -> Result<Self> {
@@ -462,6 +1030,67 @@ impl DateTime {
         self.add_duration(&Duration::from_seconds(seconds))
     }
     
+    /// Add calendar months, crossing year boundaries as needed
+    ///
+    /// The day is clamped to the target month's last valid day, so Jan 31 + 1
+    /// month is Feb 29 in leap years and Feb 28 otherwise. Time-of-day is kept.
+    pub fn add_months(&self, months: i64) -> Result<Self> {
+        let current = self.year.year as i64 * 12 + (self.month.month as i64 - 1);
+        let target = current.checked_add(months)
+            .ok_or_else(|| UtilsError::DateTime(
+                DateTimeError::arithmetic_overflow(format!("Adding {} months to {} overflows", months, self))
+            ))?;
+
+        let year_num = i32::try_from(target.div_euclid(12))
+            .map_err(|_| UtilsError::DateTime(
+                DateTimeError::arithmetic_overflow(format!("Adding {} months to {} overflows", months, self))
+            ))?;
+        let year = Year::from_number(year_num)?;
+        let month = Month::from_number(target.rem_euclid(12) as u8 + 1)?;
+        let day = Day::from_number(self.day.day.min(year.days_in_month(&month)))?;
+
+        Self::new(year, month, day, self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Subtract calendar months, with the same day clamping as `add_months`
+    pub fn subtract_months(&self, months: i64) -> Result<Self> {
+        let negated = months.checked_neg()
+            .ok_or_else(|| UtilsError::DateTime(
+                DateTimeError::arithmetic_underflow(format!("Subtracting {} months from {} underflows", months, self))
+            ))?;
+        self.add_months(negated)
+    }
+
+    /// Add calendar years (negative values go backwards), keeping time-of-day
+    ///
+    /// Feb 29 clamps to Feb 28 when the target year is not a leap year, the
+    /// common anniversary convention. Fails outside the 1900-2100 range.
+    pub fn add_years(&self, years: i32) -> Result<Self> {
+        let year = self.year.add_years(years)?;
+        let day = Day::from_number(self.day.day.min(year.days_in_month(&self.month)))?;
+
+        Self::new(year, self.month, day, self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Add whole weeks (7 days each), keeping the time of day
+    pub fn add_weeks(&self, weeks: u64) -> Result<Self> {
+        let (year, month, day) = self.add_days(Self::weeks_to_days(weeks)?)?;
+        Self::new(year, month, day, self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Subtract whole weeks (7 days each), keeping the time of day
+    pub fn subtract_weeks(&self, weeks: u64) -> Result<Self> {
+        let (year, month, day) = self.subtract_days(Self::weeks_to_days(weeks)?)?;
+        Self::new(year, month, day, self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    fn weeks_to_days(weeks: u64) -> Result<u64> {
+        weeks.checked_mul(7)
+            .ok_or_else(|| UtilsError::DateTime(
+                DateTimeError::arithmetic_overflow(format!("{} weeks is too many days", weeks))
+            ).into())
+    }
+
     pub fn add_days(&self, days: u64) -> Result<(Year, Month, Day)> {
         let mut current_year = self.year;
         let mut current_month = self.month;
@@ -511,6 +1140,30 @@ impl DateTime {
         Ok((current_year, current_month, current_day))
     }
     
+    // === Business days ===
+    
+    /// Move `days` business days forward (or backward when negative) using `calendar`
+    ///
+    /// Weekends and the calendar's holidays are skipped; time-of-day is kept.
+    /// Starting on a non-business day, one step lands on the next business day.
+    pub fn add_business_days_with_calendar(&self, days: i64, calendar: &dyn Calendar) -> Result<Self> {
+        let mut current = self.clone();
+        let mut remaining = days.unsigned_abs();
+        
+        while remaining > 0 {
+            let (year, month, day) = match days > 0 {
+                true => current.add_days(1)?,
+                false => current.subtract_days(1)?,
+            };
+            current = Self::new(year, month, day, self.hour, self.minute, self.second, self.nanosecond)?;
+            if calendar.is_business_day(&current) {
+                remaining -= 1;
+            }
+        }
+        
+        Ok(current)
+    }
+    
     // === Duration between DateTimes ===
     
     pub fn duration_since(&self, other: &DateTime) -> Option<Duration> {
@@ -527,6 +1180,214 @@ impl DateTime {
         other.duration_since(self)
     }
     
+    /// Break the time between `self` and `other` into calendar units
+    ///
+    /// The date part comes from `years_months_days_since`, so months clamp to
+    /// the end of shorter months (Jan 31 to Mar 1 is 1 month and 1 day). The
+    /// components are always the absolute difference; `negative` is set when
+    /// `other` is after `self`.
+    pub fn calendar_diff(&self, other: &DateTime) -> CalendarDiff {
+        const NANOS_PER_DAY: i64 = 86_400 * 1_000_000_000;
+        
+        fn time_of_day_nanos(dt: &DateTime) -> i64 {
+            (dt.hour as i64 * 3600 + dt.minute as i64 * 60 + dt.second as i64) * 1_000_000_000 + dt.nanosecond as i64
+        }
+        
+        let (start, end, negative) = match self >= other {
+            true => (other, self, false),
+            false => (self, other, true),
+        };
+        
+        let (mut years, mut months, mut days) = end.years_months_days_since(start);
+        
+        // Borrow a day when the end's time of day is earlier than the start's
+        let mut time_diff = time_of_day_nanos(end) - time_of_day_nanos(start);
+        if time_diff < 0 {
+            time_diff += NANOS_PER_DAY;
+            match days {
+                0 => {
+                    // end's date is a (clamped) anniversary of start's, at least one
+                    // month after it: count from the anniversary in the previous month
+                    match months {
+                        0 => {
+                            years -= 1;
+                            months = 11;
+                        }
+                        _ => months -= 1,
+                    }
+                    let prev_month_len = end.days_in_previous_month() as u32;
+                    days = prev_month_len - (start.day.day as u32).min(prev_month_len) + end.day.day as u32 - 1;
+                }
+                _ => days -= 1,
+            }
+        }
+        
+        let seconds_total = time_diff / 1_000_000_000;
+        CalendarDiff {
+            years,
+            months,
+            days,
+            hours: (seconds_total / 3600) as u32,
+            minutes: (seconds_total % 3600 / 60) as u32,
+            seconds: (seconds_total % 60) as u32,
+            negative,
+        }
+    }
+
+    // === Comparison ===
+
+    /// Check whether this DateTime falls inside `[start, end]` (inclusive)
+    ///
+    /// A degenerate window (`start == end`) matches only that exact instant;
+    /// an inverted window (`start > end`) never matches.
+    pub fn is_between(&self, start: &DateTime, end: &DateTime) -> bool {
+        start <= self && self <= end
+    }
+
+    /// Get the earlier of this DateTime and `other`
+    ///
+    /// Takes `other` by reference, so `earliest = earliest.min(&dt)` works
+    /// without cloning; these inherent versions take precedence over `Ord`'s.
+    pub fn min(self, other: &DateTime) -> DateTime {
+        match &self <= other {
+            true => self,
+            false => other.clone(),
+        }
+    }
+
+    /// Get the later of this DateTime and `other`
+    pub fn max(self, other: &DateTime) -> DateTime {
+        match &self >= other {
+            true => self,
+            false => other.clone(),
+        }
+    }
+
+    /// Restrict this DateTime to `[min, max]`
+    ///
+    /// Unlike `Ord::clamp` this never panics: an inverted range (`min > max`)
+    /// yields `max`.
+    pub fn clamp(self, min: &DateTime, max: &DateTime) -> DateTime {
+        self.max(min).min(max)
+    }
+
+    /// Get the time elapsed from this DateTime until now (UTC)
+    ///
+    /// Returns an error when this DateTime is in the future rather than a
+    /// zero duration, so clock skew or bad data is not silently hidden.
+    pub fn elapsed(&self) -> Result<Duration> {
+        let now = Self::now_utc()?;
+        let (self_nanos, now_nanos) = match (self.signed_nanos_since_epoch(), now.signed_nanos_since_epoch()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Err(UtilsError::DateTime(
+                DateTimeError::chrono_conversion(format!("Cannot convert {} to a timestamp", self))
+            ).into()),
+        };
+        
+        match now_nanos - self_nanos {
+            diff if diff < 0 => Err(UtilsError::DateTime(
+                DateTimeError::arithmetic_underflow(format!("{} is in the future", self))
+            ).into()),
+            diff => u64::try_from(diff)
+                .map(Duration::from_nanos)
+                .map_err(|_| UtilsError::DateTime(
+                    DateTimeError::arithmetic_overflow(format!("Time elapsed since {} is too large", self))
+                ).into()),
+        }
+    }
+    
+    /// Check if this DateTime is before the current UTC time
+    pub fn is_past(&self) -> bool {
+        match self.to_chrono_utc() {
+            Ok(dt) => dt < Utc::now(),
+            Err(_) => false,
+        }
+    }
+    
+    /// Check if this DateTime is after the current UTC time
+    pub fn is_future(&self) -> bool {
+        match self.to_chrono_utc() {
+            Ok(dt) => dt > Utc::now(),
+            Err(_) => false,
+        }
+    }
+    
+    /// Check whether two DateTimes are within `tolerance` of each other
+    ///
+    /// Useful in tests and deduplication where sub-second jitter shouldn't
+    /// matter. Works for datetimes on either side of the Unix epoch.
+    pub fn approx_eq(&self, other: &DateTime, tolerance: &Duration) -> bool {
+        match (self.signed_nanos_since_epoch(), other.signed_nanos_since_epoch()) {
+            (Some(a), Some(b)) => (a - b).unsigned_abs() <= tolerance.total_nanos() as u128,
+            _ => false,
+        }
+    }
+
+    /// Check equality down to the second, ignoring sub-second precision
+    ///
+    /// Unlike `approx_eq` this aligns to calendar boundaries: 14:30:45.999 and
+    /// 14:30:46.000 are one millisecond apart but not equal to the second.
+    pub fn eq_to_second(&self, other: &DateTime) -> bool {
+        self.date_part() == other.date_part()
+            && (self.hour, self.minute, self.second) == (other.hour, other.minute, other.second)
+    }
+
+    /// Check equality down to the minute, ignoring seconds and below
+    pub fn eq_to_minute(&self, other: &DateTime) -> bool {
+        self.date_part() == other.date_part() && (self.hour, self.minute) == (other.hour, other.minute)
+    }
+
+    /// Check that both fall on the same calendar day, ignoring the time of day
+    pub fn eq_to_day(&self, other: &DateTime) -> bool {
+        self.date_part() == other.date_part()
+    }
+
+    /// Calendar difference as whole (years, months, days), like a person's age
+    ///
+    /// Only the date components are considered; time-of-day is ignored. The
+    /// result is the same regardless of which DateTime is earlier. When the
+    /// starting day does not exist in a target month it is clamped to that
+    /// month's last day, so 2000-02-29 to 2001-02-28 is exactly one year.
+    pub fn years_months_days_since(&self, other: &DateTime) -> (u32, u32, u32) {
+        let self_key = (self.year.year, self.month.month, self.day.day);
+        let other_key = (other.year.year, other.month.month, other.day.day);
+        let (start, end) = match self_key >= other_key {
+            true => (other, self),
+            false => (self, other),
+        };
+
+        let mut years = end.year.year - start.year.year;
+        let mut months = end.month.month as i32 - start.month.month as i32;
+
+        let end_month_len = end.year.days_in_month(&end.month);
+        let anchor = start.day.day.min(end_month_len);
+        let days = match end.day.day >= anchor {
+            true => end.day.day - anchor,
+            false => {
+                // Borrow the remainder of the previous month
+                months -= 1;
+                let prev_month_len = end.days_in_previous_month();
+                let prev_anchor = start.day.day.min(prev_month_len);
+                prev_month_len - prev_anchor + end.day.day
+            }
+        };
+
+        if months < 0 {
+            years -= 1;
+            months += 12;
+        }
+
+        (years as u32, months as u32, days as u32)
+    }
+    
+    /// Length of the month before this DateTime's month
+    fn days_in_previous_month(&self) -> u8 {
+        match self.month.month {
+            1 => 31, // December
+            _ => self.year.days_in_month(&self.month.previous()),
+        }
+    }
+    
     // === Time-of-day as Duration ===
     
     pub fn time_since_midnight(&self) -> Duration {
@@ -542,7 +1403,7 @@ impl DateTime {
     pub fn time_until_midnight(&self) -> Duration {
         let midnight = Duration::from_hours(24);
         let current = self.time_since_midnight();
-        midnight.subtract(&current).unwrap_or(Duration::zero())
+        midnight.saturating_sub(&current)
     }
     
     // === Enhanced Duration extraction methods ===
@@ -582,12 +1443,12 @@ impl DateTime {
     
     /// Get Duration since start of week (Monday)
     pub fn time_since_week_start(&self) -> Result<Duration> {
-        let chrono_dt = self.to_chrono_naive()?;
-        let weekday = chrono_dt.weekday();
-        let days_since_monday = weekday.num_days_from_monday();
-        
-        let week_start = self.subtract_days(days_since_monday as u64)?;
-        let week_start_dt = DateTime::from_date_start_of_day(week_start.0, week_start.1, week_start.2)?;
+        self.time_since_week_start_from(Weekday::Mon)
+    }
+    
+    /// Get Duration since start of week, for weeks beginning on `start`
+    pub fn time_since_week_start_from(&self, start: Weekday) -> Result<Duration> {
+        let week_start_dt = self.week_start(start)?;
         
         self.duration_since(&week_start_dt)
             .ok_or_else(|| UtilsError::DateTime(
@@ -595,6 +1456,15 @@ impl DateTime {
             ).into())
     }
     
+    /// Get the start of this DateTime's week (00:00:00 on the most recent `start` day)
+    pub fn week_start(&self, start: Weekday) -> Result<DateTime> {
+        let weekday = self.to_chrono_naive()?.weekday();
+        let days_since_start = (weekday.num_days_from_monday() + 7 - start.num_days_from_monday()) % 7;
+        
+        let (year, month, day) = self.subtract_days(days_since_start as u64)?;
+        DateTime::from_date_start_of_day(year, month, day)
+    }
+    
     /// Get Duration until end of day
     pub fn time_until_end_of_day(&self) -> Duration {
         self.time_until_midnight()
@@ -630,7 +1500,179 @@ impl DateTime {
                 DateTimeError::arithmetic_overflow("DateTime is after year end")
             ).into())
     }
-    
+
+    // === Month boundary predicates ===
+
+    /// Check if this is the 1st of the month
+    pub fn is_first_day_of_month(&self) -> bool {
+        self.day.day == 1
+    }
+
+    /// Check if this is the last day of its month (leap-year aware)
+    pub fn is_last_day_of_month(&self) -> bool {
+        self.day.day == self.year.days_in_month(&self.month)
+    }
+
+    /// Check if this is February 29th
+    pub fn is_leap_day(&self) -> bool {
+        self.month.month == 2 && self.day.day == 29
+    }
+
+    // === Day of year ===
+
+    /// Get the day of the year (1-366)
+    pub fn day_of_year(&self) -> u16 {
+        self.year.ordinal_day(&self.month, &self.day)
+    }
+
+    /// Get the day of the week
+    pub fn weekday(&self) -> Weekday {
+        match (self.days_since_unix_epoch() + 3).rem_euclid(7) {
+            0 => Weekday::Mon,
+            1 => Weekday::Tue,
+            2 => Weekday::Wed,
+            3 => Weekday::Thu,
+            4 => Weekday::Fri,
+            5 => Weekday::Sat,
+            _ => Weekday::Sun,
+        }
+    }
+
+    /// Get the ISO 8601 week number (1-53)
+    ///
+    /// Early January days can belong to the last week of the previous year and
+    /// late December days to week 1 of the next, as in ISO 8601.
+    pub fn iso_week(&self) -> u8 {
+        let year = self.year.to_number();
+        let weekday = self.weekday().number_from_monday() as i32;
+        let week = (self.day_of_year() as i32 - weekday + 10) / 7;
+        match week {
+            0 => iso_weeks_in_year(year - 1),
+            w if w > iso_weeks_in_year(year) as i32 => 1,
+            w => w as u8,
+        }
+    }
+
+    /// Days from 1970-01-01 to this date (negative before the epoch)
+    fn days_since_unix_epoch(&self) -> i64 {
+        days_before_year(self.year.to_number()) - days_before_year(1970) + self.day_of_year() as i64 - 1
+    }
+
+    // === Quarter and semester helpers ===
+
+    /// Get the quarter number (1-4) this DateTime falls in
+    pub fn quarter(&self) -> u8 {
+        self.year.get_quarter(&self.month)
+    }
+
+    /// Get the semester number (1-2) this DateTime falls in
+    pub fn semester(&self) -> u8 {
+        match self.quarter() {
+            1 | 2 => 1,
+            _ => 2,
+        }
+    }
+
+    // === SQL-style field extraction ===
+
+    /// Extract a single field as an integer, like SQL `date_part`
+    ///
+    /// `DayOfWeek` counts from Sunday = 0 and `Week` is the ISO 8601 week, as in
+    /// Postgres. `Second` and `Epoch` are whole seconds; the fraction is dropped.
+    /// `Epoch` treats the DateTime as UTC.
+    pub fn extract(&self, field: DateField) -> i64 {
+        match field {
+            DateField::Year => self.year.to_number() as i64,
+            DateField::Quarter => self.quarter() as i64,
+            DateField::Month => self.month.to_number() as i64,
+            DateField::Week => self.iso_week() as i64,
+            DateField::Day => self.day.day as i64,
+            DateField::DayOfWeek => self.weekday().num_days_from_sunday() as i64,
+            DateField::DayOfYear => self.day_of_year() as i64,
+            DateField::Hour => self.hour as i64,
+            DateField::Minute => self.minute as i64,
+            DateField::Second => self.second as i64,
+            DateField::Epoch => {
+                self.days_since_unix_epoch() * 86_400
+                    + self.hour as i64 * 3_600
+                    + self.minute as i64 * 60
+                    + self.second as i64
+            }
+        }
+    }
+
+    /// Get the start of this DateTime's quarter (first day at 00:00:00)
+    pub fn quarter_start(&self) -> Result<DateTime> {
+        let date = self.year.quarter_start(self.quarter())?;
+        let month = Month::from_number(date.month() as u8)?;
+        let day = Day::from_naive_date(&date)?;
+
+        DateTime::from_date_start_of_day(self.year, month, day)
+    }
+
+    /// Get the end of this DateTime's quarter (last day at 23:59:59.999999999)
+    pub fn quarter_end(&self) -> Result<DateTime> {
+        let date = self.year.quarter_end(self.quarter())?;
+        let month = Month::from_number(date.month() as u8)?;
+        let day = Day::from_naive_date(&date)?;
+
+        DateTime::new(self.year, month, day, 23, 59, 59, 999_999_999)
+    }
+
+    // === Truncation and rounding ===
+
+    /// Truncate to the start of the second (drops nanoseconds)
+    pub fn truncate_to_second(&self) -> Result<Self> {
+        Self::new(self.year, self.month, self.day, self.hour, self.minute, self.second, 0)
+    }
+
+    /// Truncate to the start of the minute
+    pub fn truncate_to_minute(&self) -> Result<Self> {
+        Self::new(self.year, self.month, self.day, self.hour, self.minute, 0, 0)
+    }
+
+    /// Truncate to the start of the hour
+    pub fn truncate_to_hour(&self) -> Result<Self> {
+        Self::new(self.year, self.month, self.day, self.hour, 0, 0, 0)
+    }
+
+    /// Truncate to the start of the day (00:00:00)
+    pub fn truncate_to_day(&self) -> Result<Self> {
+        Self::from_date_start_of_day(self.year, self.month, self.day)
+    }
+
+    /// Truncate to the first day of the month at 00:00:00
+    pub fn truncate_to_month(&self) -> Result<Self> {
+        Self::from_date_start_of_day(self.year, self.month, Day::from_number(1)?)
+    }
+
+    /// Truncate to January 1st of the year at 00:00:00
+    pub fn truncate_to_year(&self) -> Result<Self> {
+        Self::from_date_start_of_day(self.year, Month::from_number(1)?, Day::from_number(1)?)
+    }
+
+    /// Round the time-of-day to the nearest multiple of `duration`
+    ///
+    /// Values exactly halfway between two multiples round up. Rounding past
+    /// midnight rolls over into the next day.
+    pub fn round_to_nearest(&self, duration: &Duration) -> Result<Self> {
+        let step = duration.total_nanos();
+        if step == 0 {
+            return Err(UtilsError::DateTime(
+                DateTimeError::invalid_time_component("Cannot round to a zero duration")
+            ).into());
+        }
+
+        let current = self.time_since_midnight().total_nanos();
+        let remainder = current % step;
+        let rounded = match remainder * 2 >= step {
+            true => current - remainder + step,
+            false => current - remainder,
+        };
+
+        self.truncate_to_day()?.add_duration(&Duration::from_nanos(rounded))
+    }
+
     // === UTC and Timestamp conversion ===
     
     /// Convert to UTC timestamp (seconds since Unix epoch)
@@ -645,6 +1687,18 @@ impl DateTime {
         Ok((chrono_dt.timestamp(), chrono_dt.timestamp_subsec_nanos()))
     }
     
+    /// Convert to a Unix timestamp in milliseconds (sub-millisecond precision is truncated)
+    pub fn to_unix_millis(&self) -> Result<i64> {
+        let (secs, nanos) = self.to_timestamp_nanos()?;
+        Ok(secs * 1_000 + (nanos / 1_000_000) as i64)
+    }
+    
+    /// Convert to a Unix timestamp in microseconds (sub-microsecond precision is truncated)
+    pub fn to_unix_micros(&self) -> Result<i64> {
+        let (secs, nanos) = self.to_timestamp_nanos()?;
+        Ok(secs * 1_000_000 + (nanos / 1_000) as i64)
+    }
+    
     /// Convert to chrono UTC DateTime
     pub fn to_chrono_utc(&self) -> Result<ChronoDateTime<Utc>> {
         let naive = self.to_chrono_naive()?;
@@ -653,40 +1707,181 @@ impl DateTime {
     
     /// Convert to chrono NaiveDateTime
     pub fn to_chrono_naive(&self) -> Result<NaiveDateTime> {
-        let naive_date = self.year.to_naive_date(&self.month, self.day.day as u32)?;
-        let naive_time = chrono::NaiveTime::from_hms_nano_opt(
+        Ok(self.to_naive_date()?.and_time(self.to_naive_time()?))
+    }
+    
+    /// Convert just the calendar date to chrono NaiveDate
+    pub fn to_naive_date(&self) -> Result<NaiveDate> {
+        self.year.to_naive_date(&self.month, self.day.day as u32)
+    }
+    
+    /// Convert just the time of day to chrono NaiveTime
+    pub fn to_naive_time(&self) -> Result<NaiveTime> {
+        NaiveTime::from_hms_nano_opt(
             self.hour as u32,
             self.minute as u32,
             self.second as u32,
             self.nanosecond
         ).ok_or_else(|| UtilsError::DateTime(
             DateTimeError::chrono_conversion("Failed to create NaiveTime from components")
-        ))?;
-        
-        Ok(naive_date.and_time(naive_time))
+        ).into())
+    }
+    
+    /// Get the calendar fields as a (Year, Month, Day) tuple
+    pub fn date_part(&self) -> (Year, Month, Day) {
+        (self.year, self.month, self.day)
     }
     
     // === Formatting ===
     
     /// Format to various standard formats
     pub fn to_format(&self, format: DateTimeFormat) -> Result<String> {
-        match format {
-            DateTimeFormat::ISO8601 => Ok(self.to_iso8601()),
-            DateTimeFormat::YYYYMMDD => Ok(self.to_yyyymmdd()),
-            DateTimeFormat::YYYY_MM_DD => Ok(self.to_yyyy_mm_dd()),
-            DateTimeFormat::DD_MM_YYYY => Ok(self.to_dd_mm_yyyy()),
-            DateTimeFormat::MM_DD_YYYY => Ok(self.to_mm_dd_yyyy()),
-            DateTimeFormat::DDMMYYYY => Ok(self.to_ddmmyyyy()),
-            DateTimeFormat::MMDDYYYY => Ok(self.to_mmddyyyy()),
-            DateTimeFormat::YYMM => Ok(self.to_yymm()),
-            DateTimeFormat::Custom(pattern) => self.to_custom_format(&pattern),
-        }
+        let mut out = String::new();
+        self.format_into(&format, &mut out)?;
+        Ok(out)
     }
     
+    /// Append this DateTime in `format` to `buf` without allocating a new String
+    ///
+    /// Meant for serialization loops that reuse one buffer across rows. On
+    /// error (an invalid custom pattern) `buf` is left as it was.
+    pub fn format_into(&self, format: &DateTimeFormat, buf: &mut String) -> Result<()> {
+        use std::fmt::Write;
+
+        let start = buf.len();
+        let (year, month, day) = (self.year.year, self.month.month, self.day.day);
+        let written = match format {
+            DateTimeFormat::ISO8601 => self.write_iso8601(buf),
+            DateTimeFormat::YYYYMMDD => write!(buf, "{}{:02}{:02}", year, month, day),
+            DateTimeFormat::YYYY_MM_DD => write!(buf, "{}-{:02}-{:02}", year, month, day),
+            DateTimeFormat::DD_MM_YYYY => write!(buf, "{:02}/{:02}/{}", day, month, year),
+            DateTimeFormat::MM_DD_YYYY => write!(buf, "{:02}/{:02}/{}", month, day, year),
+            DateTimeFormat::DDMMYYYY => write!(buf, "{:02}{:02}{}", day, month, year),
+            DateTimeFormat::MMDDYYYY => write!(buf, "{:02}{:02}{}", month, day, year),
+            DateTimeFormat::YYMM => write!(buf, "{}{:02}", self.year.to_2digit_text(), month),
+            DateTimeFormat::YYYYMM => write!(buf, "{}{:02}", year, month),
+            DateTimeFormat::YYYY_MM => write!(buf, "{}-{:02}", year, month),
+            // chrono reports an invalid pattern as a fmt::Error while writing
+            DateTimeFormat::Custom(pattern) => write!(buf, "{}", self.to_chrono_naive()?.format(pattern)),
+        };
+        written.map_err(|_| {
+            buf.truncate(start);
+            UtilsError::DateTime(
+                DateTimeError::invalid_format(format!("Cannot format {} with {:?}", self, format))
+            ).into()
+        })
+    }
+    
+    /// Format as minimal ISO8601: "2024-03-15T00:00:00Z" or "2024-03-15T14:30:45.123Z"
+    ///
+    /// The fraction is omitted when nanoseconds are zero and trailing zeros
+    /// are trimmed otherwise. Use `to_iso8601_nanos` for fixed-width output.
     pub fn to_iso8601(&self) -> String {
-        format!("{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}Z",
+        let mut out = String::with_capacity(30);
+        // Writing into a String cannot fail
+        let _ = self.write_iso8601(&mut out);
+        out
+    }
+    
+    /// Format as ISO8601 with all nine fractional digits: "2024-03-15T14:30:45.123000000Z"
+    pub fn to_iso8601_nanos(&self) -> String {
+        let mut out = String::with_capacity(30);
+        // Writing into a String cannot fail
+        let _ = self.write_iso8601_nanos_local(&mut out);
+        out.push('Z');
+        out
+    }
+    
+    /// Format as ISO8601 with exactly `precision` fractional digits (0-9, truncated)
+    ///
+    /// `3` gives "2024-03-15T14:30:45.123Z"; `0` drops the fraction and the
+    /// dot. Precisions above 9 are capped at 9.
+    pub fn iso8601_with_precision(&self, precision: u8) -> String {
+        format!("{:.*}", precision as usize, self.iso8601())
+    }
+    
+    /// Borrow this DateTime as an ISO8601 `Display` adapter that honors `{:.N}` precision
+    pub fn iso8601(&self) -> Iso8601Display<'_> {
+        Iso8601Display::new(self)
+    }
+    
+    fn write_iso8601(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        self.write_iso8601_local(w)?;
+        w.write_char('Z')
+    }
+    
+    /// ISO8601 without the "Z" suffix and with trailing fraction zeros trimmed
+    fn write_iso8601_local(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        let (second, nanosecond) = self.iso_second_and_nanos();
+        write!(w, "{}-{:02}-{:02}T{:02}:{:02}:{:02}",
             self.year.year, self.month.month, self.day.day,
-            self.hour, self.minute, self.second, self.nanosecond)
+            self.hour, self.minute, second)?;
+        if nanosecond > 0 {
+            // Trim trailing zeros: 123_000_000 is written as ".123"
+            let (mut digits, mut width) = (nanosecond, 9);
+            while digits % 10 == 0 {
+                digits /= 10;
+                width -= 1;
+            }
+            write!(w, ".{:0width$}", digits, width = width)?;
+        }
+        Ok(())
+    }
+    
+    /// ISO8601 without the "Z" suffix and with all nine fractional digits
+    fn write_iso8601_nanos_local(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        let (second, nanosecond) = self.iso_second_and_nanos();
+        write!(w, "{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}",
+            self.year.year, self.month.month, self.day.day,
+            self.hour, self.minute, second, nanosecond)
+    }
+    
+    fn write_iso8601_precision(&self, w: &mut impl std::fmt::Write, precision: usize) -> std::fmt::Result {
+        let (second, nanosecond) = self.iso_second_and_nanos();
+        write!(w, "{}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year.year, self.month.month, self.day.day,
+            self.hour, self.minute, second)?;
+        if precision > 0 {
+            let digits = format!("{:09}", nanosecond);
+            write!(w, ".{}", &digits[..precision])?;
+        }
+        w.write_char('Z')
+    }
+    
+    /// Second and nanosecond as written in ISO8601, with a leap second shown as 60
+    fn iso_second_and_nanos(&self) -> (u8, u32) {
+        match self.is_leap_second() {
+            true => (60, self.nanosecond - 1_000_000_000),
+            false => (self.second, self.nanosecond),
+        }
+    }
+    
+    /// Format as RFC 2822 (email/HTTP style): "Fri, 15 Mar 2024 14:30:45 +0000"
+    pub fn to_rfc2822(&self) -> Result<String> {
+        Ok(self.to_chrono_utc()?.to_rfc2822())
+    }
+    
+    /// Format as strict RFC 3339: "2024-03-15T14:30:45Z" or "2024-03-15T14:30:45.123Z"
+    ///
+    /// A zero fraction is omitted; otherwise 3, 6 or 9 digits are used as needed.
+    pub fn to_rfc3339(&self) -> Result<String> {
+        Ok(self.to_chrono_utc()?.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+    
+    /// Format as a fixed-width 8-byte DATASUS/DBF date field (YYYYMMDD ASCII)
+    ///
+    /// Digits are written straight into the array, so a hand-built value with
+    /// out-of-range fields yields a wrong date rather than a panic.
+    pub fn to_sus_date(&self) -> [u8; 8] {
+        let mut field = [b'0'; 8];
+        for (slot, digit) in field[..4].iter_mut().zip(self.year.text_4d.bytes()) {
+            *slot = digit;
+        }
+        field[4] = b'0' + self.month.month / 10 % 10;
+        field[5] = b'0' + self.month.month % 10;
+        field[6] = b'0' + self.day.day / 10 % 10;
+        field[7] = b'0' + self.day.day % 10;
+        field
     }
     
     pub fn to_yyyymmdd(&self) -> String {
@@ -717,6 +1912,14 @@ impl DateTime {
         format!("{}{:02}", self.year.to_2digit_text(), self.month.month)
     }
     
+    pub fn to_yyyymm(&self) -> String {
+        format!("{}{:02}", self.year.year, self.month.month)
+    }
+    
+    pub fn to_yyyy_mm(&self) -> String {
+        format!("{}-{:02}", self.year.year, self.month.month)
+    }
+    
     pub fn to_custom_format(&self, pattern: &str) -> Result<String> {
         let naive = self.to_chrono_naive()?;
         Ok(naive.format(pattern).to_string())
@@ -736,6 +1939,18 @@ impl DateTime {
             self.hour, self.minute, self.second)
     }
     
+    /// English with the weekday: "Friday, March 15, 2024 at 14:30:45"
+    pub fn to_readable_full_en(&self) -> Result<String> {
+        let weekday = self.day.to_weekday_name_en(&self.month, &self.year)?;
+        Ok(format!("{}, {}", weekday, self.to_readable_en()))
+    }
+    
+    /// Brazilian Portuguese with the weekday: "Sexta-feira, 15 de Março de 2024 às 14:30:45"
+    pub fn to_readable_full_ptbr(&self) -> Result<String> {
+        let weekday = self.day.to_weekday_name_ptbr(&self.month, &self.year)?;
+        Ok(format!("{}, {}", weekday, self.to_readable_ptbr()))
+    }
+    
     // === Helper methods ===
     
     fn total_nanos_since_epoch(&self) -> Option<u64> {
@@ -751,6 +1966,11 @@ impl DateTime {
         }
     }
     
+    fn signed_nanos_since_epoch(&self) -> Option<i128> {
+        let chrono_dt = self.to_chrono_utc().ok()?;
+        Some(chrono_dt.timestamp() as i128 * 1_000_000_000 + chrono_dt.timestamp_subsec_nanos() as i128)
+    }
+    
     /// Validation methods
     pub fn is_valid<T: DateTimeValidatable>(input: T) -> bool {
         input.is_valid_datetime()
@@ -760,6 +1980,30 @@ impl DateTime {
     pub fn builder() -> DateTimeBuilder {
         DateTimeBuilder::new()
     }
+    
+    /// Create a builder pre-populated with this DateTime's fields
+    pub fn to_builder(&self) -> DateTimeBuilder {
+        DateTimeBuilder::from_datetime(self)
+    }
+}
+
+// === Calendar arithmetic helpers ===
+
+/// Days from 0001-01-01 to January 1st of `year` in the proleptic Gregorian calendar
+fn days_before_year(year: i32) -> i64 {
+    let y = year as i64 - 1;
+    y * 365 + y / 4 - y / 100 + y / 400
+}
+
+/// Number of ISO 8601 weeks in `year` (52 or 53)
+fn iso_weeks_in_year(year: i32) -> u8 {
+    // A year has 53 weeks when it starts on a Thursday, or is a leap year starting on a Wednesday
+    let jan1_weekday = |y: i32| (days_before_year(y) % 7) as u8; // 0 = Monday
+    let is_leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    match (jan1_weekday(year), is_leap) {
+        (3, _) | (2, true) => 53,
+        _ => 52,
+    }
 }
 
 // === Display implementation ===
@@ -768,7 +2012,85 @@ impl std::fmt::Display for DateTime {
         write!(f, "{}", self.to_iso8601())
     }
 }
+
+// === Ordering implementation ===
+impl DateTime {
+    fn sort_key(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        (self.year.year, self.month.month, self.day.day, self.hour, self.minute, self.second, self.nanosecond)
+    }
+}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    /// Chronological order, comparing components from year down to nanosecond
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+// === Chrono conversion trait implementations ===
+impl TryFrom<NaiveDateTime> for DateTime {
+    type Error = SharedError;
+
+    fn try_from(value: NaiveDateTime) -> Result<Self> {
+        Self::from_chrono_naive(&value)
+    }
+}
+
+impl TryFrom<ChronoDateTime<Utc>> for DateTime {
+    type Error = SharedError;
+
+    fn try_from(value: ChronoDateTime<Utc>) -> Result<Self> {
+        Self::from_chrono_utc(&value)
+    }
+}
+
+impl TryFrom<DateTime> for NaiveDateTime {
+    type Error = SharedError;
+
+    fn try_from(value: DateTime) -> Result<Self> {
+        value.to_chrono_naive()
+    }
+}
+
+impl TryFrom<DateTime> for ChronoDateTime<Utc> {
+    type Error = SharedError;
+
+    fn try_from(value: DateTime) -> Result<Self> {
+        value.to_chrono_utc()
+    }
+}
+
+// === Component tuple conversions ===
+/// Raw components in `DateTime::new` order: (year, month, day, hour, minute, second, nanosecond)
+impl From<DateTime> for (i32, u8, u8, u8, u8, u8, u32) {
+    fn from(value: DateTime) -> Self {
+        (value.year.year, value.month.month, value.day.day, value.hour, value.minute, value.second, value.nanosecond)
+    }
+}
+
+/// Validates every component through `DateTime::new`
+impl TryFrom<(i32, u8, u8, u8, u8, u8, u32)> for DateTime {
+    type Error = SharedError;
+
+    fn try_from(value: (i32, u8, u8, u8, u8, u8, u32)) -> Result<Self> {
+        let (year, month, day, hour, minute, second, nanosecond) = value;
+        Self::new(Year::from_number(year)?, Month::from_number(month)?, Day::from_number(day)?, hour, minute, second, nanosecond)
+    }
+}
+
 /// DateTime Builder for ergonomic construction
+///
+/// Setters apply in call order and the last writer wins: `at_noon().hour(9)`
+/// builds 09:00. `build` accepts that silently; `build_strict` rejects a
+/// builder where a field setter (`hour`, `minute`, `second`, `nanosecond`,
+/// `millisecond`, `microsecond`) or another whole-time setter changed a value
+/// set by `time`, `at_time`, `at_noon` or `at_midnight`.
 pub struct DateTimeBuilder {
     year: Option<Year>,
     month: Option<Month>,
@@ -777,6 +2099,8 @@ pub struct DateTimeBuilder {
     minute: u8,
     second: u8,
     nanosecond: u32,
+    time_setter: Option<&'static str>,
+    conflicts: Vec<String>,
 }
 
 impl DateTimeBuilder {
@@ -789,6 +2113,23 @@ impl DateTimeBuilder {
             minute: 0,
             second: 0,
             nanosecond: 0,
+            time_setter: None,
+            conflicts: Vec::new(),
+        }
+    }
+    
+    /// Start from an existing DateTime, carrying over every field including nanoseconds
+    pub fn from_datetime(dt: &DateTime) -> Self {
+        Self {
+            year: Some(dt.year),
+            month: Some(dt.month),
+            day: Some(dt.day),
+            hour: dt.hour,
+            minute: dt.minute,
+            second: dt.second,
+            nanosecond: dt.nanosecond,
+            time_setter: None,
+            conflicts: Vec::new(),
         }
     }
     
@@ -812,58 +2153,91 @@ impl DateTimeBuilder {
     // === Time setters ===
     
     pub fn hour(mut self, hour: u8) -> Self {
+        self.note_field_override("hour", self.hour as u32, hour as u32);
         self.hour = hour;
         self
     }
     
     pub fn minute(mut self, minute: u8) -> Self {
+        self.note_field_override("minute", self.minute as u32, minute as u32);
         self.minute = minute;
         self
     }
     
     pub fn second(mut self, second: u8) -> Self {
+        self.note_field_override("second", self.second as u32, second as u32);
         self.second = second;
         self
     }
     
     pub fn nanosecond(mut self, nanosecond: u32) -> Self {
+        self.note_field_override("nanosecond", self.nanosecond, nanosecond);
         self.nanosecond = nanosecond;
         self
     }
     
+    /// Set the sub-second part in milliseconds (values >= 1000 fail at build time)
+    pub fn millisecond(self, millisecond: u32) -> Self {
+        self.nanosecond(millisecond.saturating_mul(1_000_000))
+    }
+    
+    /// Set the sub-second part in microseconds (values >= 1_000_000 fail at build time)
+    pub fn microsecond(self, microsecond: u32) -> Self {
+        self.nanosecond(microsecond.saturating_mul(1_000))
+    }
+    
     // === Convenience setters ===
     
+    /// Set hour, minute and second; the sub-second part is left unchanged
     pub fn time(mut self, hour: u8, minute: u8, second: u8) -> Self {
-        self.hour = hour;
-        self.minute = minute;
-        self.second = second;
+        let nanosecond = self.nanosecond;
+        self.set_time("time()", hour, minute, second, nanosecond);
         self
     }
     
     pub fn at_time(mut self, duration: Duration) -> Self {
-        self.hour = duration.hours() as u8;
-        self.minute = duration.minutes() as u8;
-        self.second = duration.seconds() as u8;
-        self.nanosecond = duration.nanos() as u32;
+        self.set_time(
+            "at_time()",
+            duration.hours() as u8,
+            duration.minutes() as u8,
+            duration.seconds() as u8,
+            duration.nanos() as u32,
+        );
         self
     }
     
     pub fn at_noon(mut self) -> Self {
-        self.hour = 12;
-        self.minute = 0;
-        self.second = 0;
-        self.nanosecond = 0;
+        self.set_time("at_noon()", 12, 0, 0, 0);
         self
     }
     
     pub fn at_midnight(mut self) -> Self {
-        self.hour = 0;
-        self.minute = 0;
-        self.second = 0;
-        self.nanosecond = 0;
+        self.set_time("at_midnight()", 0, 0, 0, 0);
         self
     }
     
+    // === Conflict tracking ===
+    
+    /// Apply a whole-time setter, recording a conflict if it changes a time set by another one
+    fn set_time(&mut self, setter: &'static str, hour: u8, minute: u8, second: u8, nanosecond: u32) {
+        let current = (self.hour, self.minute, self.second, self.nanosecond);
+        if let Some(previous) = self.time_setter.filter(|_| current != (hour, minute, second, nanosecond)) {
+            self.conflicts.push(format!("{} overrides the time set by {}", setter, previous));
+        }
+        self.hour = hour;
+        self.minute = minute;
+        self.second = second;
+        self.nanosecond = nanosecond;
+        self.time_setter = Some(setter);
+    }
+    
+    /// Record a conflict if a field setter changes a value set by a whole-time setter
+    fn note_field_override(&mut self, field: &str, current: u32, new: u32) {
+        if let Some(setter) = self.time_setter.filter(|_| current != new) {
+            self.conflicts.push(format!("{}({}) overrides {} {} set by {}", field, new, field, current, setter));
+        }
+    }
+    
     // === Date convenience methods ===
     
     pub fn today() -> Result<Self> {
@@ -905,6 +2279,18 @@ impl DateTimeBuilder {
         
         DateTime::new(year, month, day, self.hour, self.minute, self.second, self.nanosecond)
     }
+    
+    /// Build like `build`, but fail if any setter silently overrode a whole-time setter
+    pub fn build_strict(self) -> Result<DateTime> {
+        match self.conflicts.is_empty() {
+            true => self.build(),
+            false => Err(UtilsError::DateTime(
+                DateTimeError::invalid_time_component(format!(
+                    "Conflicting time setters: {}", self.conflicts.join("; ")
+                ))
+            ).into()),
+        }
+    }
 }
 
 impl Default for DateTimeBuilder {
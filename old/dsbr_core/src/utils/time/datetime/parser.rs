@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use crate::core::Result;
+use crate::utils::time::datetime::{DateTime, DateTimeFormat};
+
+/// DateTimeParser parses with a bounded cache of previously seen inputs
+///
+/// Meant for bulk ingestion where the same string repeats across many rows
+/// (e.g. a DATASUS file's competência). Each cached entry costs the input
+/// string plus one `DateTime`, so memory grows with `capacity`, not with the
+/// number of rows. When the cache is full it is cleared and refilled, which
+/// keeps lookups cheap but means a column with more distinct values than
+/// `capacity` gets little benefit. Failed parses are never cached.
+#[derive(Debug, Clone)]
+pub struct DateTimeParser {
+    capacity: usize,
+    len: usize,
+    cache: HashMap<DateTimeFormat, HashMap<String, DateTime>>,
+}
+
+impl DateTimeParser {
+    /// Default number of cached entries
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Create a parser with `DEFAULT_CAPACITY`
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Create a parser caching at most `capacity` entries (0 disables caching)
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { capacity, len: 0, cache: HashMap::new() }
+    }
+
+    /// Parse `input` with `format`, returning the cached value on repeat
+    pub fn parse(&mut self, input: &str, format: DateTimeFormat) -> Result<DateTime> {
+        if let Some(cached) = self.cache.get(&format).and_then(|entries| entries.get(input)) {
+            return Ok(cached.clone());
+        }
+
+        let parsed = DateTime::from_format(input, format.clone())?;
+        if self.capacity > 0 {
+            if self.len >= self.capacity {
+                self.clear();
+            }
+            self.cache.entry(format).or_default().insert(input.to_string(), parsed.clone());
+            self.len += 1;
+        }
+        Ok(parsed)
+    }
+
+    /// Get the number of cached entries
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Drop every cached entry
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.len = 0;
+    }
+}
+
+impl Default for DateTimeParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
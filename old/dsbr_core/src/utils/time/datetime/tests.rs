@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use crate::utils::time::{DateTime, DateTimeFormat, Duration, Year, Month, Day};
+    use crate::utils::time::{CalendarDiff, DateField, DateTime, DateTimeFormat, Duration, Year, Month, Day};
 
     #[test]
     fn test_datetime_creation() {
@@ -117,6 +117,8 @@ mod tests {
         assert_eq!(dt.to_format(DateTimeFormat::DD_MM_YYYY).unwrap(), "15/03/2024");
         assert_eq!(dt.to_format(DateTimeFormat::MM_DD_YYYY).unwrap(), "03/15/2024");
         assert_eq!(dt.to_format(DateTimeFormat::YYMM).unwrap(), "2403");
+        assert_eq!(dt.to_format(DateTimeFormat::YYYYMM).unwrap(), "202403");
+        assert_eq!(dt.to_format(DateTimeFormat::YYYY_MM).unwrap(), "2024-03");
     }
 
     #[test]
@@ -213,4 +215,1447 @@ mod tests {
         assert_eq!(dt.second(), dt_from_timestamp.second());
         assert_eq!(dt.nanosecond(), dt_from_timestamp.nanosecond());
     }
+
+    #[test]
+    fn test_datetime_quarter_and_semester() {
+        let dt = DateTime::from("2024-05-20T10:15:00Z").unwrap();
+        assert_eq!(dt.quarter(), 2);
+        assert_eq!(dt.semester(), 1);
+
+        let dt = DateTime::from("2024-11-02").unwrap();
+        assert_eq!(dt.quarter(), 4);
+        assert_eq!(dt.semester(), 2);
+    }
+
+    #[test]
+    fn test_datetime_quarter_bounds() {
+        let dt = DateTime::from("2024-05-20T10:15:00Z").unwrap();
+
+        let start = dt.quarter_start().unwrap();
+        assert_eq!(start.to_yyyy_mm_dd(), "2024-04-01");
+        assert_eq!(start.hour(), 0);
+        assert_eq!(start.nanosecond(), 0);
+
+        let end = dt.quarter_end().unwrap();
+        assert_eq!(end.to_yyyy_mm_dd(), "2024-06-30");
+        assert_eq!(end.hour(), 23);
+        assert_eq!(end.minute(), 59);
+        assert_eq!(end.second(), 59);
+        assert_eq!(end.nanosecond(), 999_999_999);
+    }
+
+    #[test]
+    fn test_datetime_truncation() {
+        let dt = DateTime::from("2024-03-15T14:30:45.123456789Z").unwrap();
+
+        assert_eq!(dt.truncate_to_second().unwrap().to_iso8601(), "2024-03-15T14:30:45Z");
+        assert_eq!(dt.truncate_to_minute().unwrap().to_iso8601(), "2024-03-15T14:30:00Z");
+        assert_eq!(dt.truncate_to_hour().unwrap().to_iso8601(), "2024-03-15T14:00:00Z");
+        assert_eq!(dt.truncate_to_day().unwrap().to_iso8601(), "2024-03-15T00:00:00Z");
+        assert_eq!(dt.truncate_to_month().unwrap().to_iso8601(), "2024-03-01T00:00:00Z");
+        assert_eq!(dt.truncate_to_year().unwrap().to_iso8601(), "2024-01-01T00:00:00Z");
+
+        // Truncation is idempotent
+        let hour = dt.truncate_to_hour().unwrap();
+        assert_eq!(hour.truncate_to_hour().unwrap(), hour);
+    }
+
+    #[test]
+    fn test_datetime_round_to_nearest() {
+        let dt = DateTime::from("2024-03-15T14:07:29Z").unwrap();
+        let rounded = dt.round_to_nearest(&Duration::from_minutes(15)).unwrap();
+        assert_eq!(rounded.hour(), 14);
+        assert_eq!(rounded.minute(), 0);
+
+        // Exactly half rounds up
+        let dt = DateTime::from("2024-03-15T14:07:30Z").unwrap();
+        let rounded = dt.round_to_nearest(&Duration::from_minutes(15)).unwrap();
+        assert_eq!(rounded.minute(), 15);
+
+        // Rounding past midnight rolls into the next day
+        let dt = DateTime::from("2024-03-15T23:50:00Z").unwrap();
+        let rounded = dt.round_to_nearest(&Duration::from_hours(1)).unwrap();
+        assert_eq!(rounded.to_yyyy_mm_dd(), "2024-03-16");
+        assert_eq!(rounded.hour(), 0);
+
+        assert!(dt.round_to_nearest(&Duration::zero()).is_err());
+    }
+
+    #[test]
+    fn test_datetime_with_mutators() {
+        let dt = DateTime::from("2024-01-31T14:30:45Z").unwrap();
+
+        let changed = dt.with_hour(9).unwrap().with_minute(5).unwrap().with_second(1).unwrap();
+        assert_eq!(changed.hour(), 9);
+        assert_eq!(changed.minute(), 5);
+        assert_eq!(changed.second(), 1);
+        assert_eq!(changed.day().day, 31);
+
+        let changed = dt.with_nanosecond(500).unwrap();
+        assert_eq!(changed.nanosecond(), 500);
+
+        let changed = dt.with_year(Year::from_number(2020).unwrap()).unwrap();
+        assert_eq!(changed.year().year, 2020);
+
+        let changed = dt.with_day(Day::from_number(1).unwrap()).unwrap();
+        assert_eq!(changed.day().day, 1);
+
+        // Original is untouched
+        assert_eq!(dt.hour(), 14);
+
+        assert!(dt.with_hour(24).is_err());
+        assert!(dt.with_nanosecond(1_000_000_000).is_err());
+    }
+
+    #[test]
+    fn test_datetime_with_month_invalid_day() {
+        let jan_31 = DateTime::from("2024-01-31").unwrap();
+
+        // February has no 31st day
+        assert!(jan_31.with_month(Month::from_number(2).unwrap()).is_err());
+        assert!(jan_31.with_month(Month::from_number(3).unwrap()).is_ok());
+
+        // Feb 29 is only valid in leap years
+        let feb_29 = DateTime::from("2024-02-29").unwrap();
+        assert!(feb_29.with_year(Year::from_number(2023).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_datetime_parse_any() {
+        let dt = DateTime::parse_any("2024-03-15T14:30:45Z").unwrap();
+        assert_eq!(dt.hour(), 14);
+
+        assert_eq!(DateTime::parse_any("2024-03-15").unwrap().to_yyyy_mm_dd(), "2024-03-15");
+        assert_eq!(DateTime::parse_any("20240315").unwrap().to_yyyy_mm_dd(), "2024-03-15");
+        assert_eq!(DateTime::parse_any("03/15/2024").unwrap().to_yyyy_mm_dd(), "2024-03-15");
+        assert_eq!(DateTime::parse_any("2403").unwrap().to_yyyy_mm_dd(), "2024-03-01");
+
+        // Ambiguous slash dates prefer DD/MM/YYYY
+        assert_eq!(DateTime::parse_any("01/02/2024").unwrap().to_yyyy_mm_dd(), "2024-02-01");
+    }
+
+    #[test]
+    fn test_datetime_parse_any_error_lists_attempts() {
+        let err = DateTime::parse_any("not a date").unwrap_err().to_string();
+        assert!(err.contains("ISO8601"));
+        assert!(err.contains("DD/MM/YYYY"));
+        assert!(err.contains("YYMM"));
+    }
+
+    #[test]
+    fn test_datetime_yyyymmdd_rejects_non_digits() {
+        let err = DateTime::from_yyyymmdd("2024ab15").unwrap_err().to_string();
+        assert!(err.contains("Invalid format"));
+        assert!(err.contains("8 ASCII digits"));
+
+        assert!(DateTime::from_yyyymmdd("2024-3-1").is_err());
+        assert!(DateTime::from_yyyymmdd("+2024031").is_err());
+    }
+
+    #[test]
+    fn test_datetime_yyyymmdd_out_of_range_year() {
+        let err = DateTime::from_yyyymmdd("18500101").unwrap_err().to_string();
+        assert!(err.contains("Invalid format"));
+        assert!(err.contains("1900-2100"));
+
+        let err = DateTime::from_yyyymmdd("00000101").unwrap_err().to_string();
+        assert!(err.contains("outside the supported range"));
+
+        assert!(DateTime::from_yyyymmdd("19000101").is_ok());
+        assert!(DateTime::from_yyyymmdd("21001231").is_ok());
+    }
+
+    #[test]
+    fn test_datetime_millisecond_precision() {
+        let year = Year::from_number(2024).unwrap();
+        let month = Month::from_number(3).unwrap();
+        let day = Day::from_number(15).unwrap();
+
+        let dt = DateTime::from_date_and_time_millis(year, month, day, 14, 30, 45, 123).unwrap();
+        assert_eq!(dt.nanosecond(), 123_000_000);
+        assert_eq!(dt.millisecond(), 123);
+        assert_eq!(dt.microsecond(), 123_000);
+
+        assert!(DateTime::from_date_and_time_millis(year, month, day, 14, 30, 45, 1000).is_err());
+    }
+
+    #[test]
+    fn test_datetime_builder_sub_second_setters() {
+        let dt = DateTime::builder()
+            .date(2024, 3, 15).unwrap()
+            .millisecond(250)
+            .build().unwrap();
+        assert_eq!(dt.nanosecond(), 250_000_000);
+
+        let dt = DateTime::builder()
+            .date(2024, 3, 15).unwrap()
+            .microsecond(250_500)
+            .build().unwrap();
+        assert_eq!(dt.nanosecond(), 250_500_000);
+        assert_eq!(dt.millisecond(), 250);
+        assert_eq!(dt.microsecond(), 250_500);
+
+        assert!(DateTime::builder().date(2024, 3, 15).unwrap().millisecond(1000).build().is_err());
+        assert!(DateTime::builder().date(2024, 3, 15).unwrap().microsecond(1_000_000).build().is_err());
+        assert!(DateTime::builder().date(2024, 3, 15).unwrap().millisecond(u32::MAX).build().is_err());
+    }
+
+    #[test]
+    fn test_datetime_chrono_try_from() {
+        use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+        let naive = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap().and_hms_opt(14, 30, 45).unwrap();
+        let dt = DateTime::try_from(naive).unwrap();
+        assert_eq!(dt.to_iso8601(), "2024-03-15T14:30:45Z");
+
+        let back: NaiveDateTime = dt.clone().try_into().unwrap();
+        assert_eq!(back, naive);
+
+        let utc = Utc.with_ymd_and_hms(2024, 3, 15, 14, 30, 45).unwrap();
+        let dt_utc: DateTime = utc.try_into().unwrap();
+        assert_eq!(dt_utc, dt);
+
+        let back_utc: chrono::DateTime<Utc> = dt_utc.try_into().unwrap();
+        assert_eq!(back_utc, utc);
+
+        // Outside the supported year range
+        let too_early = NaiveDate::from_ymd_opt(1800, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert!(DateTime::try_from(too_early).is_err());
+    }
+
+    #[test]
+    fn test_zoned_datetime_from_iso8601_with_offset() {
+        use crate::utils::time::ZonedDateTime;
+
+        let zoned = ZonedDateTime::from_iso8601("2024-03-15T14:30:45-03:00").unwrap();
+        assert_eq!(zoned.local().hour(), 14);
+        assert_eq!(zoned.offset().local_minus_utc(), -3 * 3600);
+
+        let utc = zoned.to_utc().unwrap();
+        assert_eq!(utc.hour(), 17);
+        assert_eq!(utc.to_yyyy_mm_dd(), "2024-03-15");
+
+        assert_eq!(zoned.to_string(), "2024-03-15T14:30:45-03:00");
+        assert_eq!(zoned.to_iso8601(), zoned.local().to_iso8601().replace('Z', "-03:00"));
+        assert_eq!(zoned.to_iso8601_nanos(), "2024-03-15T14:30:45.000000000-03:00");
+        assert!(ZonedDateTime::from_iso8601("2024-03-15T14:30:45").is_err());
+
+        let fractional = ZonedDateTime::from_iso8601("2024-03-15T14:30:45.120+05:30").unwrap();
+        assert_eq!(fractional.to_string(), "2024-03-15T14:30:45.12+05:30");
+        assert_eq!(fractional.to_iso8601_nanos(), "2024-03-15T14:30:45.120000000+05:30");
+    }
+
+    #[test]
+    fn test_zoned_datetime_with_timezone() {
+        use crate::utils::time::ZonedDateTime;
+        use chrono::FixedOffset;
+
+        let utc = DateTime::from("2024-03-15T01:00:00Z").unwrap();
+        let sao_paulo = FixedOffset::west_opt(3 * 3600).unwrap();
+
+        // Crossing midnight backwards into the previous day
+        let zoned = ZonedDateTime::from_utc(&utc, sao_paulo).unwrap();
+        assert_eq!(zoned.local().to_yyyy_mm_dd(), "2024-03-14");
+        assert_eq!(zoned.local().hour(), 22);
+
+        let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+        let in_tokyo = zoned.with_timezone(tokyo).unwrap();
+        assert_eq!(in_tokyo.local().hour(), 10);
+        assert_eq!(in_tokyo.to_utc().unwrap(), utc);
+    }
+
+    #[test]
+    fn test_zoned_datetime_from_local_in_timezone() {
+        use crate::utils::time::ZonedDateTime;
+        use chrono::FixedOffset;
+
+        let local = DateTime::from("2024-03-15T14:30:00Z").unwrap();
+        let offset = FixedOffset::west_opt(3 * 3600).unwrap();
+        let zoned = ZonedDateTime::from_local_in_timezone(&local, &offset).unwrap();
+        assert_eq!(zoned.offset(), offset);
+        assert_eq!(zoned.local(), &local);
+    }
+
+    #[test]
+    fn test_datetime_years_months_days_since() {
+        let leap_day = DateTime::from("2000-02-29").unwrap();
+        let next_year = DateTime::from("2001-02-28").unwrap();
+        assert_eq!(next_year.years_months_days_since(&leap_day), (1, 0, 0));
+
+        let jan_31 = DateTime::from("2020-01-31").unwrap();
+        let mar_1 = DateTime::from("2020-03-01").unwrap();
+        assert_eq!(mar_1.years_months_days_since(&jan_31), (0, 1, 1));
+
+        let feb_29 = DateTime::from("2020-02-29").unwrap();
+        assert_eq!(feb_29.years_months_days_since(&jan_31), (0, 1, 0));
+
+        // Borrowing across a year boundary
+        let start = DateTime::from("2020-01-15").unwrap();
+        let end = DateTime::from("2021-01-10").unwrap();
+        assert_eq!(end.years_months_days_since(&start), (0, 11, 26));
+
+        // Order-independent and ignores time-of-day
+        let birth = DateTime::from("1990-06-15T23:00:00Z").unwrap();
+        let today = DateTime::from("2024-03-15T01:00:00Z").unwrap();
+        assert_eq!(today.years_months_days_since(&birth), (33, 9, 0));
+        assert_eq!(birth.years_months_days_since(&today), (33, 9, 0));
+        assert_eq!(today.years_months_days_since(&today), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_datetime_add_months_across_years() {
+        let dec = DateTime::from("2023-12-10T08:15:00Z").unwrap();
+        let may = dec.add_months(5).unwrap();
+        assert_eq!(may.to_yyyy_mm_dd(), "2024-05-10");
+        assert_eq!(may.hour(), 8);
+        assert_eq!(may.minute(), 15);
+
+        assert_eq!(may.subtract_months(5).unwrap(), dec);
+        assert_eq!(dec.add_months(-12).unwrap().to_yyyy_mm_dd(), "2022-12-10");
+        assert_eq!(dec.add_months(0).unwrap(), dec);
+    }
+
+    #[test]
+    fn test_datetime_add_months_clamps_day() {
+        let jan_31 = DateTime::from("2024-01-31").unwrap();
+        assert_eq!(jan_31.add_months(1).unwrap().to_yyyy_mm_dd(), "2024-02-29");
+        assert_eq!(jan_31.add_months(13).unwrap().to_yyyy_mm_dd(), "2025-02-28");
+        assert_eq!(jan_31.add_months(3).unwrap().to_yyyy_mm_dd(), "2024-04-30");
+
+        let mar_31 = DateTime::from("2023-03-31").unwrap();
+        assert_eq!(mar_31.subtract_months(1).unwrap().to_yyyy_mm_dd(), "2023-02-28");
+
+        // Year bounds are respected
+        let late = DateTime::from("2100-12-01").unwrap();
+        assert!(late.add_months(1).is_err());
+        assert!(late.add_months(i64::MAX).is_err());
+        assert!(late.subtract_months(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn test_datetime_add_years_leap_day() {
+        let leap_day = DateTime::from("2024-02-29T10:20:30Z").unwrap();
+
+        let next = leap_day.add_years(1).unwrap();
+        assert_eq!(next.to_yyyy_mm_dd(), "2025-02-28");
+        assert_eq!(next.hour(), 10);
+        assert_eq!(next.minute(), 20);
+        assert_eq!(next.second(), 30);
+
+        assert_eq!(leap_day.add_years(4).unwrap().to_yyyy_mm_dd(), "2028-02-29");
+        assert_eq!(leap_day.add_years(-1).unwrap().to_yyyy_mm_dd(), "2023-02-28");
+
+        // 1900-2100 bounds
+        assert!(leap_day.add_years(77).is_err());
+        assert!(leap_day.add_years(-125).is_err());
+        assert_eq!(leap_day.add_years(76).unwrap().to_yyyy_mm_dd(), "2100-02-28");
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let expected = DateTime::from_iso8601("2024-03-15T14:30:45Z").unwrap();
+        let computed = DateTime::from_iso8601("2024-03-15T14:30:45.000400Z").unwrap();
+        let earlier = DateTime::from_iso8601("2024-03-15T14:30:44.999600Z").unwrap();
+
+        let one_ms = Duration::from_millis(1);
+        assert!(expected.approx_eq(&computed, &one_ms));
+        assert!(computed.approx_eq(&expected, &one_ms));
+        assert!(expected.approx_eq(&earlier, &one_ms));
+
+        // Tolerance is inclusive
+        assert!(computed.approx_eq(&earlier, &Duration::from_micros(800)));
+        assert!(!computed.approx_eq(&earlier, &Duration::from_micros(799)));
+
+        // Zero tolerance means exact equality
+        assert!(expected.approx_eq(&expected, &Duration::zero()));
+        assert!(!expected.approx_eq(&computed, &Duration::zero()));
+
+        // Works before the Unix epoch
+        let old_a = DateTime::from_iso8601("1965-06-01T00:00:00Z").unwrap();
+        let old_b = DateTime::from_iso8601("1965-06-01T00:00:00.5Z").unwrap();
+        assert!(old_a.approx_eq(&old_b, &Duration::from_secs(1)));
+        assert!(!old_a.approx_eq(&old_b, &Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_datetime_ordering() {
+        let a = DateTime::from_iso8601("2024-03-15T14:30:45Z").unwrap();
+        let b = DateTime::from_iso8601("2024-03-15T14:30:45.000000001Z").unwrap();
+        let c = DateTime::from_iso8601("2024-12-01T00:00:00Z").unwrap();
+
+        assert!(a < b && b < c);
+        assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+
+        let mut sorted = vec![c.clone(), a.clone(), b.clone()];
+        sorted.sort();
+        assert_eq!(sorted, vec![a.clone(), b.clone(), c.clone()]);
+
+        assert_eq!(a.clone().min(&c), a);
+        assert_eq!(a.clone().max(&c), c);
+        assert_eq!(c.clone().min(&a), a);
+        assert_eq!(a.clone().min(&a), a);
+
+        // Ord's by-value versions are still available
+        assert_eq!(std::cmp::min(c.clone(), a.clone()), a);
+        assert_eq!(Ord::max(a.clone(), c.clone()), c);
+    }
+
+    #[test]
+    fn test_datetime_clamp_and_is_between() {
+        let start = DateTime::from_iso8601("2024-01-01T00:00:00Z").unwrap();
+        let end = DateTime::from_iso8601("2024-03-31T23:59:59Z").unwrap();
+        let inside = DateTime::from_iso8601("2024-02-15T12:00:00Z").unwrap();
+        let before = DateTime::from_iso8601("2023-12-31T23:59:59Z").unwrap();
+        let after = DateTime::from_iso8601("2024-04-01T00:00:00Z").unwrap();
+
+        assert_eq!(inside.clone().clamp(&start, &end), inside);
+        assert_eq!(before.clone().clamp(&start, &end), start);
+        assert_eq!(after.clone().clamp(&start, &end), end);
+        assert_eq!(inside.clone().clamp(&inside, &inside), inside);
+
+        // An inverted range yields `max` rather than panicking
+        assert_eq!(inside.clone().clamp(&end, &start), start);
+
+        assert!(inside.is_between(&start, &end));
+        assert!(start.is_between(&start, &end));
+        assert!(end.is_between(&start, &end));
+        assert!(!before.is_between(&start, &end));
+        assert!(!after.is_between(&start, &end));
+
+        // Degenerate window matches only that instant
+        assert!(start.is_between(&start, &start));
+        assert!(!inside.is_between(&inside.clone(), &start));
+
+        // Inverted window never matches
+        assert!(!inside.is_between(&end, &start));
+    }
+
+    #[test]
+    fn test_unix_millis_and_micros() {
+        let dt = DateTime::from_unix_millis(1_710_513_045_123).unwrap();
+        assert_eq!(dt, DateTime::from_iso8601("2024-03-15T14:30:45.123Z").unwrap());
+        assert_eq!(dt.to_unix_millis().unwrap(), 1_710_513_045_123);
+
+        let dt = DateTime::from_unix_micros(1_710_513_045_123_456).unwrap();
+        assert_eq!(dt.nanosecond, 123_456_000);
+        assert_eq!(dt.to_unix_micros().unwrap(), 1_710_513_045_123_456);
+        assert_eq!(dt.to_unix_millis().unwrap(), 1_710_513_045_123);
+
+        // Pre-epoch values floor towards the earlier instant
+        let dt = DateTime::from_unix_millis(-1).unwrap();
+        assert_eq!(dt, DateTime::from_iso8601("1969-12-31T23:59:59.999Z").unwrap());
+        assert_eq!(dt.to_unix_millis().unwrap(), -1);
+
+        let dt = DateTime::from_unix_micros(-1).unwrap();
+        assert_eq!(dt, DateTime::from_iso8601("1969-12-31T23:59:59.999999Z").unwrap());
+        assert_eq!(dt.to_unix_micros().unwrap(), -1);
+
+        let dt = DateTime::from_unix_millis(-1_500).unwrap();
+        assert_eq!((dt.second, dt.nanosecond), (58, 500_000_000));
+        assert_eq!(dt.to_unix_millis().unwrap(), -1_500);
+
+        assert_eq!(DateTime::from_unix_millis(0).unwrap().to_unix_micros().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_leap_second_parsing() {
+        use chrono::Timelike;
+
+        // Rejected by default
+        assert!(DateTime::from_iso8601("2016-12-31T23:59:60Z").is_err());
+
+        let dt = DateTime::from_iso8601_allow_leap_second("2016-12-31T23:59:60Z").unwrap();
+        assert!(dt.is_leap_second());
+        assert_eq!((dt.hour, dt.minute, dt.second), (23, 59, 59));
+        assert_eq!(dt.nanosecond, 1_000_000_000);
+        assert_eq!((dt.millisecond(), dt.microsecond()), (0, 0));
+        assert_eq!(dt.to_iso8601(), "2016-12-31T23:59:60Z");
+
+        // Fractional leap second round-trips through ISO8601 and chrono
+        let dt = DateTime::from_iso8601_allow_leap_second("2016-12-31T23:59:60.25Z").unwrap();
+        assert_eq!(dt.nanosecond, 1_250_000_000);
+        assert_eq!((dt.millisecond(), dt.microsecond()), (250, 250_000));
+        let half = DateTime::from_iso8601_allow_leap_second("2016-12-31T23:59:60.5Z").unwrap();
+        assert_eq!((half.millisecond(), half.microsecond()), (500, 500_000));
+        let reparsed = DateTime::from_iso8601_allow_leap_second(&dt.to_iso8601()).unwrap();
+        assert_eq!(reparsed, dt);
+        let naive = dt.to_chrono_naive().unwrap();
+        assert_eq!((naive.second(), naive.nanosecond()), (59, 1_250_000_000));
+
+        // Ordinary timestamps behave exactly like from_iso8601
+        let normal = DateTime::from_iso8601_allow_leap_second("2024-03-15T14:30:45.5Z").unwrap();
+        assert!(!normal.is_leap_second());
+        assert_eq!(normal, DateTime::from_iso8601("2024-03-15T14:30:45.5Z").unwrap());
+        assert!(DateTime::from_iso8601_allow_leap_second("not a date").is_err());
+    }
+
+    #[test]
+    fn test_rfc2822_round_trip() {
+        let dt = DateTime::from_iso8601("2024-03-15T14:30:45Z").unwrap();
+        assert_eq!(dt.to_rfc2822().unwrap(), "Fri, 15 Mar 2024 14:30:45 +0000");
+        assert_eq!(DateTime::from_rfc2822("Fri, 15 Mar 2024 14:30:45 +0000").unwrap(), dt);
+
+        // Offsets are normalized to UTC
+        assert_eq!(DateTime::from_rfc2822("Fri, 15 Mar 2024 11:30:45 -0300").unwrap(), dt);
+        assert!(DateTime::from_rfc2822("2024-03-15T14:30:45Z").is_err());
+    }
+
+    #[test]
+    fn test_rfc3339_round_trip() {
+        let dt = DateTime::from_iso8601("2024-03-15T14:30:45Z").unwrap();
+        assert_eq!(dt.to_rfc3339().unwrap(), "2024-03-15T14:30:45Z");
+
+        let millis = DateTime::from_iso8601("2024-03-15T14:30:45.123Z").unwrap();
+        assert_eq!(millis.to_rfc3339().unwrap(), "2024-03-15T14:30:45.123Z");
+
+        let nanos = DateTime::from_iso8601("2024-03-15T14:30:45.123456789Z").unwrap();
+        assert_eq!(nanos.to_rfc3339().unwrap(), "2024-03-15T14:30:45.123456789Z");
+
+        assert_eq!(DateTime::from_rfc3339("2024-03-15T14:30:45Z").unwrap(), dt);
+        assert_eq!(DateTime::from_rfc3339("2024-03-15T11:30:45-03:00").unwrap(), dt);
+        assert_eq!(DateTime::from_rfc3339(&nanos.to_rfc3339().unwrap()).unwrap(), nanos);
+        assert!(DateTime::from_rfc3339("2024-03-15 14:30:45").is_err());
+    }
+
+    #[test]
+    fn test_to_iso8601_minimal_fraction() {
+        let midnight = DateTime::from_iso8601("2024-03-15").unwrap();
+        assert_eq!(midnight.to_iso8601(), "2024-03-15T00:00:00Z");
+        assert_eq!(midnight.to_string(), "2024-03-15T00:00:00Z");
+        assert_eq!(midnight.to_iso8601_nanos(), "2024-03-15T00:00:00.000000000Z");
+
+        let millis = DateTime::from_iso8601("2024-03-15T14:30:45.123Z").unwrap();
+        assert_eq!(millis.to_iso8601(), "2024-03-15T14:30:45.123Z");
+        assert_eq!(millis.to_iso8601_nanos(), "2024-03-15T14:30:45.123000000Z");
+
+        let half = DateTime::from_iso8601("2024-03-15T14:30:45.5Z").unwrap();
+        assert_eq!(half.to_iso8601(), "2024-03-15T14:30:45.5Z");
+
+        let one_nano = DateTime::from_iso8601("2024-03-15T14:30:45.000000001Z").unwrap();
+        assert_eq!(one_nano.to_iso8601(), "2024-03-15T14:30:45.000000001Z");
+
+        // Both forms parse back to the same value
+        assert_eq!(DateTime::from_iso8601(&millis.to_iso8601()).unwrap(), millis);
+        assert_eq!(DateTime::from_iso8601(&millis.to_iso8601_nanos()).unwrap(), millis);
+    }
+
+    #[test]
+    fn test_parse_column() {
+        let column = ["15/03/2024", "not a date", "01/01/2000", "32/01/2024"];
+        let (values, errors) = DateTime::parse_column(column, DateTimeFormat::DD_MM_YYYY);
+
+        assert_eq!(values.len(), 4);
+        assert_eq!(values[0], Some(DateTime::from_iso8601("2024-03-15").unwrap()));
+        assert_eq!(values[1], None);
+        assert_eq!(values[2], Some(DateTime::from_iso8601("2000-01-01").unwrap()));
+        assert_eq!(values[3], None);
+
+        let failed_rows: Vec<usize> = errors.iter().map(|(index, _)| *index).collect();
+        assert_eq!(failed_rows, vec![1, 3]);
+
+        // Works with borrowed owned strings too
+        let owned = vec!["20240315".to_string()];
+        let (values, errors) = DateTime::parse_column(owned.iter().map(String::as_str), DateTimeFormat::YYYYMMDD);
+        assert_eq!(values, vec![Some(DateTime::from_iso8601("2024-03-15").unwrap())]);
+        assert!(errors.is_empty());
+
+        let (values, errors) = DateTime::parse_column(Vec::<&str>::new(), DateTimeFormat::ISO8601);
+        assert!(values.is_empty() && errors.is_empty());
+    }
+
+    #[test]
+    fn test_now_local() {
+        let before = DateTime::from_chrono_naive(&chrono::Local::now().naive_local()).unwrap();
+        let now = DateTime::now_local().unwrap();
+        let after = DateTime::from_chrono_naive(&chrono::Local::now().naive_local()).unwrap();
+
+        assert!(now.is_between(&before, &after));
+    }
+
+    #[test]
+    fn test_calendars() {
+        use crate::utils::time::{BrazilianCalendar, Calendar, WeekendsOnlyCalendar};
+        use crate::utils::time::datetime::calendar::easter_sunday;
+
+        assert_eq!(easter_sunday(2024), chrono::NaiveDate::from_ymd_opt(2024, 3, 31));
+        assert_eq!(easter_sunday(2025), chrono::NaiveDate::from_ymd_opt(2025, 4, 20));
+
+        let brazil = BrazilianCalendar::new();
+        let date = |s: &str| DateTime::from_iso8601(s).unwrap();
+
+        // Fixed and Easter-based national holidays
+        assert!(brazil.is_holiday(&date("2024-09-07")));
+        assert!(brazil.is_holiday(&date("2024-02-12"))); // Carnival Monday
+        assert!(brazil.is_holiday(&date("2024-02-13"))); // Carnival Tuesday
+        assert!(brazil.is_holiday(&date("2024-03-29"))); // Good Friday
+        assert!(brazil.is_holiday(&date("2024-05-30"))); // Corpus Christi
+        assert!(!brazil.is_holiday(&date("2024-02-14"))); // Ash Wednesday
+
+        // Consciência Negra is national only from 2024
+        assert!(brazil.is_holiday(&date("2024-11-20")));
+        assert!(!brazil.is_holiday(&date("2023-11-20")));
+
+        // Weekends are never business days
+        assert!(!WeekendsOnlyCalendar.is_business_day(&date("2024-03-16")));
+        assert!(WeekendsOnlyCalendar.is_business_day(&date("2024-03-29")));
+        assert!(!brazil.is_business_day(&date("2024-03-29")));
+
+        // Local holidays, e.g. São Paulo's anniversary
+        let sao_paulo = BrazilianCalendar::new().with_local_holidays([(1, 25)]);
+        assert!(sao_paulo.is_holiday(&date("2024-01-25")));
+        assert!(!brazil.is_holiday(&date("2024-01-25")));
+    }
+
+    #[test]
+    fn test_add_business_days_with_calendar() {
+        use crate::utils::time::{BrazilianCalendar, WeekendsOnlyCalendar};
+
+        let brazil = BrazilianCalendar::new();
+        let date = |s: &str| DateTime::from_iso8601(s).unwrap();
+
+        // Thursday before Easter: Good Friday is skipped only by the Brazilian calendar
+        let thursday = date("2024-03-28T09:30:00");
+        assert_eq!(thursday.add_business_days_with_calendar(1, &WeekendsOnlyCalendar).unwrap(), date("2024-03-29T09:30:00"));
+        assert_eq!(thursday.add_business_days_with_calendar(1, &brazil).unwrap(), date("2024-04-01T09:30:00"));
+
+        // Backwards across Carnival and a weekend
+        let after_carnival = date("2024-02-15");
+        assert_eq!(after_carnival.add_business_days_with_calendar(-2, &brazil).unwrap(), date("2024-02-09"));
+        assert_eq!(date("2024-02-19").add_business_days_with_calendar(-1, &WeekendsOnlyCalendar).unwrap(), date("2024-02-16"));
+
+        // Local holidays plug in
+        let sao_paulo = BrazilianCalendar::new().with_local_holidays([(1, 25)]);
+        assert_eq!(date("2024-01-24").add_business_days_with_calendar(1, &sao_paulo).unwrap(), date("2024-01-26"));
+
+        // Zero is a no-op, even on a weekend
+        let saturday = date("2024-03-16");
+        assert_eq!(saturday.add_business_days_with_calendar(0, &brazil).unwrap(), saturday);
+    }
+
+    #[test]
+    fn test_day_of_year() {
+        use chrono::Datelike;
+
+        assert_eq!(DateTime::from_iso8601("2024-01-01").unwrap().day_of_year(), 1);
+        assert_eq!(DateTime::from_iso8601("2024-03-01").unwrap().day_of_year(), 61);
+        assert_eq!(DateTime::from_iso8601("2023-03-01").unwrap().day_of_year(), 60);
+        assert_eq!(DateTime::from_iso8601("2024-12-31T23:59:59Z").unwrap().day_of_year(), 366);
+        assert_eq!(DateTime::from_iso8601("2023-12-31").unwrap().day_of_year(), 365);
+
+        // Agrees with chrono
+        let dt = DateTime::from_iso8601("2024-08-17T10:00:00Z").unwrap();
+        assert_eq!(dt.day_of_year() as u32, dt.to_chrono_naive().unwrap().ordinal());
+    }
+
+    #[test]
+    fn test_week_start_configurable() {
+        use chrono::Weekday;
+
+        let date = |s: &str| DateTime::from_iso8601(s).unwrap();
+
+        // Saturday, Sunday and Monday around the 2024-03-17 boundary
+        let saturday = date("2024-03-16T12:00:00");
+        let sunday = date("2024-03-17T10:00:00");
+        let monday = date("2024-03-18T08:30:00");
+
+        assert_eq!(saturday.week_start(Weekday::Sun).unwrap(), date("2024-03-10"));
+        assert_eq!(sunday.week_start(Weekday::Sun).unwrap(), date("2024-03-17"));
+        assert_eq!(monday.week_start(Weekday::Sun).unwrap(), date("2024-03-17"));
+
+        assert_eq!(saturday.week_start(Weekday::Mon).unwrap(), date("2024-03-11"));
+        assert_eq!(sunday.week_start(Weekday::Mon).unwrap(), date("2024-03-11"));
+        assert_eq!(monday.week_start(Weekday::Mon).unwrap(), date("2024-03-18"));
+
+        assert_eq!(sunday.time_since_week_start_from(Weekday::Sun).unwrap(), Duration::from_hours(10));
+        assert_eq!(sunday.time_since_week_start_from(Weekday::Mon).unwrap(), Duration::from_hours(6 * 24 + 10));
+
+        // Monday remains the default
+        assert_eq!(monday.time_since_week_start().unwrap(), monday.time_since_week_start_from(Weekday::Mon).unwrap());
+        assert_eq!(monday.time_since_week_start().unwrap(), Duration::from_minutes(8 * 60 + 30));
+    }
+
+    #[test]
+    fn test_datetime_interval() {
+        use crate::utils::time::DateTimeInterval;
+
+        let date = |s: &str| DateTime::from_iso8601(s).unwrap();
+        let interval = |a: &str, b: &str| DateTimeInterval::new(date(a), date(b)).unwrap();
+
+        assert!(DateTimeInterval::new(date("2024-03-02"), date("2024-03-01")).is_err());
+        let instant = interval("2024-03-01", "2024-03-01");
+        assert_eq!(instant.duration(), Some(Duration::zero()));
+
+        let morning = interval("2024-03-15T08:00:00", "2024-03-15T12:00:00");
+        let midday = interval("2024-03-15T11:00:00", "2024-03-15T14:00:00");
+        let afternoon = interval("2024-03-15T12:00:00", "2024-03-15T18:00:00");
+        let evening = interval("2024-03-15T19:00:00", "2024-03-15T22:00:00");
+
+        assert_eq!(morning.duration(), Some(Duration::from_hours(4)));
+        let across_epoch = interval("1969-12-31T12:00:00", "1970-01-01T12:00:00");
+        assert_eq!(across_epoch.duration(), Some(Duration::from_hours(24)));
+        let pre_epoch = interval("1900-01-01", "1900-01-02");
+        assert_eq!(pre_epoch.duration(), Some(Duration::from_days(1)));
+        assert!(morning.contains(&date("2024-03-15T08:00:00")));
+        assert!(morning.contains(&date("2024-03-15T12:00:00")));
+        assert!(!morning.contains(&date("2024-03-15T12:00:01")));
+
+        assert!(morning.overlaps(&midday));
+        assert!(morning.overlaps(&afternoon)); // touching endpoints
+        assert!(!morning.overlaps(&evening));
+
+        assert_eq!(morning.intersection(&midday), Some(interval("2024-03-15T11:00:00", "2024-03-15T12:00:00")));
+        assert_eq!(morning.intersection(&afternoon), Some(interval("2024-03-15T12:00:00", "2024-03-15T12:00:00")));
+        assert_eq!(morning.intersection(&evening), None);
+
+        assert_eq!(morning.union(&midday), Some(interval("2024-03-15T08:00:00", "2024-03-15T14:00:00")));
+        assert_eq!(midday.union(&morning), morning.union(&midday));
+        assert_eq!(morning.union(&evening), None);
+    }
+
+    #[test]
+    fn test_from_yyyy_mm_dd_strict() {
+        let dt = DateTime::from_yyyy_mm_dd_strict("2024-03-05").unwrap();
+        assert_eq!(dt, DateTime::from_yyyy_mm_dd("2024-03-05").unwrap());
+        assert!(DateTime::from_yyyy_mm_dd_strict("2024-02-29").is_ok());
+
+        // Lenient parser accepts this, strict one does not
+        assert!(DateTime::from_yyyy_mm_dd("2024-3-5").is_ok());
+
+        let cases = [
+            ("2024-3-5", "4-digit year and 2-digit month and day"),
+            ("24-03-05", "4-digit year and 2-digit month and day"),
+            ("2024/03/05", "4-digit year and 2-digit month and day"),
+            ("2024-13-01", "month 13 must be between 01 and 12"),
+            ("2024-00-10", "month 0 must be between 01 and 12"),
+            ("2024-02-30", "day 30 must be between 01 and 29 for February 2024"),
+            ("2023-02-29", "day 29 must be between 01 and 28 for February 2023"),
+            ("2024-04-00", "day 0 must be between 01 and 30 for April 2024"),
+            ("1850-01-01", "year 1850 is outside"),
+        ];
+        for (input, expected) in cases {
+            let err = DateTime::from_yyyy_mm_dd_strict(input).unwrap_err().to_string();
+            assert!(err.contains("Invalid date"), "{}: {}", input, err);
+            assert!(err.contains(expected), "{}: {}", input, err);
+        }
+    }
+
+    #[test]
+    fn test_sus_date_fields() {
+        let dt = DateTime::from_sus_date(b"20240315").unwrap().unwrap();
+        assert_eq!(dt, DateTime::from_iso8601("2024-03-15").unwrap());
+        assert_eq!(&dt.to_sus_date(), b"20240315");
+        assert_eq!(&DateTime::from_iso8601("1900-01-09").unwrap().to_sus_date(), b"19000109");
+
+        // Out-of-range public fields give a wrong date, not a panic
+        let mut hand_built = dt.clone();
+        hand_built.day.day = 100;
+        assert_eq!(&hand_built.to_sus_date(), b"20240300");
+
+        // YYYYMM maps to the first of the month, padded or not
+        let first = DateTime::from_iso8601("2024-03-01").unwrap();
+        assert_eq!(DateTime::from_sus_date(b"202403").unwrap(), Some(first.clone()));
+        assert_eq!(DateTime::from_sus_date(b"202403  ").unwrap(), Some(first.clone()));
+        assert_eq!(DateTime::from_sus_date(b"202403\0\0").unwrap(), Some(first));
+
+        // Missing-data conventions
+        assert_eq!(DateTime::from_sus_date(b"        ").unwrap(), None);
+        assert_eq!(DateTime::from_sus_date(b"00000000").unwrap(), None);
+        assert_eq!(DateTime::from_sus_date(b"\0\0\0\0\0\0\0\0").unwrap(), None);
+        assert_eq!(DateTime::from_sus_date(b"").unwrap(), None);
+
+        assert!(DateTime::from_sus_date(b"2024031").is_err());
+        assert!(DateTime::from_sus_date(b"2024-3-1").is_err());
+        assert!(DateTime::from_sus_date(b"20241301").is_err());
+        assert!(DateTime::from_sus_date(&[0xff, 0xfe, b'1', b'2', b'3', b'4', b'5', b'6']).is_err());
+    }
+
+    #[test]
+    fn test_from_components_checked() {
+        use chrono::Weekday;
+
+        let parts = || (
+            Year::from_number(2024).unwrap(),
+            Month::from_number(3).unwrap(),
+            Day::from_number(15).unwrap(),
+        );
+
+        // 2024-03-15 is a Friday
+        let (y, m, d) = parts();
+        let dt = DateTime::from_components_checked(y, m, d, 14, 30, 45, 0, Weekday::Fri).unwrap();
+        assert_eq!(dt, DateTime::from_iso8601("2024-03-15T14:30:45Z").unwrap());
+
+        let (y, m, d) = parts();
+        let err = DateTime::from_components_checked(y, m, d, 14, 30, 45, 0, Weekday::Thu)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("2024-03-15 is a Fri"), "unexpected error: {}", err);
+        assert!(err.contains("Thu"), "unexpected error: {}", err);
+
+        // Invalid components still fail before the weekday check
+        let (y, m, d) = parts();
+        assert!(DateTime::from_components_checked(y, m, d, 24, 0, 0, 0, Weekday::Fri).is_err());
+    }
+
+    #[test]
+    fn test_elapsed_and_past_future() {
+        let an_hour_ago = DateTime::from_timestamp(chrono::Utc::now().timestamp() - 3_600).unwrap();
+        let elapsed = an_hour_ago.elapsed().unwrap();
+        assert!(!elapsed.is_shorter_than(&Duration::from_hours(1)));
+        assert!(elapsed.is_shorter_than(&Duration::from_hours(1).checked_add(&Duration::from_minutes(1)).unwrap()));
+        assert!(an_hour_ago.is_past());
+        assert!(!an_hour_ago.is_future());
+
+        // Pre-epoch records work too
+        let old = DateTime::from_iso8601("1965-06-01").unwrap();
+        assert!(old.elapsed().unwrap().is_longer_than(&Duration::from_days(365 * 50)));
+
+        let tomorrow = DateTime::from_timestamp(chrono::Utc::now().timestamp() + 86_400).unwrap();
+        assert!(tomorrow.elapsed().is_err());
+        assert!(tomorrow.is_future());
+        assert!(!tomorrow.is_past());
+    }
+
+    #[test]
+    fn test_naive_date_and_time_splits() {
+        let dt = DateTime::new(
+            Year::from_number(2024).unwrap(),
+            Month::from_number(3).unwrap(),
+            Day::from_number(15).unwrap(),
+            14, 30, 45, 123_456_789,
+        ).unwrap();
+
+        assert_eq!(dt.to_naive_date().unwrap(), chrono::NaiveDate::from_ymd_opt(2024, 3, 15).unwrap());
+        assert_eq!(
+            dt.to_naive_time().unwrap(),
+            chrono::NaiveTime::from_hms_nano_opt(14, 30, 45, 123_456_789).unwrap()
+        );
+        assert_eq!(
+            dt.to_naive_date().unwrap().and_time(dt.to_naive_time().unwrap()),
+            dt.to_chrono_naive().unwrap()
+        );
+
+        let (year, month, day) = dt.date_part();
+        assert_eq!(year.year, 2024);
+        assert_eq!(month.month, 3);
+        assert_eq!(day.day, 15);
+    }
+
+    #[test]
+    fn test_min_and_max_value() {
+        use crate::utils::time::year::{MIN_YEAR, MAX_YEAR};
+
+        let min = DateTime::min_value();
+        let max = DateTime::max_value();
+        assert_eq!(min.year.year, MIN_YEAR);
+        assert_eq!(max.year.year, MAX_YEAR);
+        assert_eq!(min.to_iso8601_nanos(), format!("{}-01-01T00:00:00.000000000Z", MIN_YEAR));
+        assert_eq!(max.to_iso8601_nanos(), format!("{}-12-31T23:59:59.999999999Z", MAX_YEAR));
+
+        // Both are valid DateTimes that round-trip through the checked constructor
+        assert_eq!(DateTime::new(min.year, min.month, min.day, 0, 0, 0, 0).unwrap(), min);
+        assert_eq!(DateTime::new(max.year, max.month, max.day, 23, 59, 59, 999_999_999).unwrap(), max);
+
+        let samples = [
+            DateTime::from_timestamp(1_700_000_000).unwrap(),
+            DateTime::from_timestamp(0).unwrap(),
+            DateTime::from_timestamp(2_000_000_000).unwrap(),
+        ];
+        let mut earliest = DateTime::max_value();
+        let mut latest = DateTime::min_value();
+        for dt in &samples {
+            earliest = earliest.min(dt);
+            latest = latest.max(dt);
+        }
+        assert_eq!(earliest, samples[1]);
+        assert_eq!(latest, samples[2]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parse_column_parallel_matches_sequential() {
+        let column: Vec<String> = (0..5_000)
+            .map(|i| match i % 7 {
+                0 => "not a date".to_string(),
+                3 => format!("31/02/{}", 2000 + i % 50),
+                _ => format!("{:02}/{:02}/{}", 1 + i % 28, 1 + i % 12, 1950 + i % 100),
+            })
+            .collect();
+
+        let (sequential, sequential_errors) =
+            DateTime::parse_column(column.iter().map(String::as_str), DateTimeFormat::DD_MM_YYYY);
+        let (parallel, parallel_errors) =
+            DateTime::parse_column_parallel(&column, DateTimeFormat::DD_MM_YYYY);
+
+        assert_eq!(parallel, sequential);
+        let indices = |errors: &[(usize, crate::core::SharedError)]| errors.iter().map(|(i, _)| *i).collect::<Vec<_>>();
+        assert_eq!(indices(&parallel_errors), indices(&sequential_errors));
+        assert!(!parallel_errors.is_empty());
+    }
+
+    #[test]
+    fn test_calendar_diff() {
+        let dt = |s: &str| DateTime::from_iso8601(s).unwrap();
+
+        let diff = dt("2024-05-18T12:00:00").calendar_diff(&dt("2024-03-15T10:30:15"));
+        assert_eq!((diff.years, diff.months, diff.days), (0, 2, 3));
+        assert_eq!((diff.hours, diff.minutes, diff.seconds), (1, 29, 45));
+        assert!(!diff.negative);
+
+        // Month lengths are respected, clamping to the shorter month
+        let diff = dt("2023-03-01T00:00:00").calendar_diff(&dt("2023-01-31T00:00:00"));
+        assert_eq!((diff.months, diff.days), (1, 1));
+        let diff = dt("2023-04-30T00:00:00").calendar_diff(&dt("2023-03-31T00:00:00"));
+        assert_eq!((diff.months, diff.days), (1, 0));
+        let diff = dt("2024-03-01T00:00:00").calendar_diff(&dt("2024-02-01T00:00:00"));
+        assert_eq!((diff.months, diff.days), (1, 0));
+
+        // An earlier time of day borrows a day, across month and year boundaries
+        let diff = dt("2024-01-01T09:00:00").calendar_diff(&dt("2023-12-31T10:00:00"));
+        assert_eq!((diff.years, diff.months, diff.days, diff.hours), (0, 0, 0, 23));
+        let diff = dt("2025-03-01T09:00:00").calendar_diff(&dt("2020-01-31T10:00:00"));
+        assert_eq!((diff.years, diff.months, diff.days, diff.hours), (5, 1, 0, 23));
+        let diff = dt("2023-02-28T09:00:00").calendar_diff(&dt("2023-01-31T10:00:00"));
+        assert_eq!((diff.years, diff.months, diff.days, diff.hours), (0, 0, 27, 23));
+        let diff = dt("2024-04-01T09:00:00").calendar_diff(&dt("2024-03-01T10:00:00"));
+        assert_eq!((diff.years, diff.months, diff.days, diff.hours), (0, 0, 30, 23));
+        let diff = dt("2024-01-15T09:00:00").calendar_diff(&dt("2023-01-15T10:00:00"));
+        assert_eq!((diff.years, diff.months, diff.days, diff.hours), (0, 11, 30, 23));
+
+        // The date part always agrees with years_months_days_since
+        let (start, end) = (dt("2000-02-29T00:00:00"), dt("2001-02-28T00:00:00"));
+        let diff = end.calendar_diff(&start);
+        assert_eq!((diff.years, diff.months, diff.days), end.years_months_days_since(&start));
+
+        // Reversed order gives the same components with the sign flag set
+        let forward = dt("2024-03-15T00:00:00").calendar_diff(&dt("2022-01-10T00:00:00"));
+        let backward = dt("2022-01-10T00:00:00").calendar_diff(&dt("2024-03-15T00:00:00"));
+        assert_eq!((forward.years, forward.months, forward.days), (2, 2, 5));
+        assert_eq!(CalendarDiff { negative: false, ..backward }, forward);
+        assert!(backward.negative);
+
+        assert!(dt("2024-03-15T00:00:00").calendar_diff(&dt("2024-03-15T00:00:00")).is_zero());
+    }
+
+    #[test]
+    fn test_calendar_diff_readable() {
+        let diff = CalendarDiff { months: 2, days: 3, ..Default::default() };
+        assert_eq!(diff.to_readable_en(), "2 months, 3 days");
+        assert_eq!(diff.to_readable_ptbr(), "2 meses e 3 dias");
+
+        let diff = CalendarDiff { years: 1, months: 1, hours: 1, seconds: 10, ..Default::default() };
+        assert_eq!(diff.to_readable_en(), "1 year, 1 month, 1 hour, 10 seconds");
+        assert_eq!(diff.to_readable_ptbr(), "1 ano, 1 mês, 1 hora e 10 segundos");
+
+        let diff = CalendarDiff { minutes: 5, negative: true, ..Default::default() };
+        assert_eq!(diff.to_readable_en(), "5 minutes");
+        assert_eq!(diff.to_readable_ptbr(), "5 minutos");
+
+        assert_eq!(CalendarDiff::default().to_readable_en(), "0 seconds");
+        assert_eq!(CalendarDiff::default().to_readable_ptbr(), "0 segundos");
+    }
+
+    #[test]
+    fn test_datetime_year_month_parsing() {
+        let first = DateTime::from_iso8601("2024-03-01").unwrap();
+        assert_eq!(DateTime::from_format("202403", DateTimeFormat::YYYYMM).unwrap(), first);
+        assert_eq!(DateTime::from_format("2024-03", DateTimeFormat::YYYY_MM).unwrap(), first);
+        assert_eq!(DateTime::from_yyyymm("190001").unwrap().to_yyyy_mm_dd(), "1900-01-01");
+
+        // Shape is validated before anything else
+        for bad in ["20243", "2024033", "2024ab", "2024-3", ""] {
+            assert!(DateTime::from_yyyymm(bad).is_err(), "{}", bad);
+        }
+        for bad in ["2024-3", "24-03", "2024/03", "2024-03-01", "2024-Mar", "202403"] {
+            assert!(DateTime::from_yyyy_mm(bad).is_err(), "{}", bad);
+        }
+
+        // Month and year ranges
+        assert!(DateTime::from_yyyymm("202413").is_err());
+        assert!(DateTime::from_yyyymm("202400").is_err());
+        assert!(DateTime::from_yyyymm("185001").unwrap_err().to_string().contains("outside the supported range"));
+        assert!(DateTime::from_yyyy_mm("2101-01").is_err());
+
+        // Round trip through the formatters
+        assert_eq!(DateTime::from_yyyymm(&first.to_yyyymm()).unwrap(), first);
+        assert_eq!(DateTime::from_yyyy_mm(&first.to_yyyy_mm()).unwrap(), first);
+
+        // Both are tried by parse_any
+        assert_eq!(DateTime::parse_any("202403").unwrap(), first);
+        assert_eq!(DateTime::parse_any("2024-03").unwrap(), first);
+    }
+
+    #[test]
+    fn test_iso8601_with_precision() {
+        let dt = DateTime::from_iso8601("2024-03-15T14:30:45.123456789Z").unwrap();
+        assert_eq!(dt.iso8601_with_precision(0), "2024-03-15T14:30:45Z");
+        assert_eq!(dt.iso8601_with_precision(3), "2024-03-15T14:30:45.123Z");
+        assert_eq!(dt.iso8601_with_precision(6), "2024-03-15T14:30:45.123456Z");
+        assert_eq!(dt.iso8601_with_precision(9), "2024-03-15T14:30:45.123456789Z");
+        assert_eq!(dt.iso8601_with_precision(12), "2024-03-15T14:30:45.123456789Z");
+
+        // Truncates rather than rounds, and pads short fractions
+        let dt = DateTime::from_iso8601("2024-03-15T14:30:45.9999Z").unwrap();
+        assert_eq!(dt.iso8601_with_precision(3), "2024-03-15T14:30:45.999Z");
+        let dt = DateTime::from_iso8601("2024-03-15T14:30:45.5Z").unwrap();
+        assert_eq!(dt.iso8601_with_precision(3), "2024-03-15T14:30:45.500Z");
+        let dt = DateTime::from_iso8601("2024-03-15T14:30:45Z").unwrap();
+        assert_eq!(dt.iso8601_with_precision(3), "2024-03-15T14:30:45.000Z");
+
+        // The adapter honors {:.N} and falls back to to_iso8601 without one
+        let dt = DateTime::from_iso8601("2024-03-15T14:30:45.120Z").unwrap();
+        assert_eq!(format!("{:.3}", dt.iso8601()), "2024-03-15T14:30:45.120Z");
+        assert_eq!(format!("{:.1}", dt.iso8601()), "2024-03-15T14:30:45.1Z");
+        assert_eq!(format!("{}", dt.iso8601()), dt.to_iso8601());
+
+        // Leap seconds keep the :60 second
+        let leap = DateTime::from_iso8601_allow_leap_second("2016-12-31T23:59:60.250Z").unwrap();
+        assert_eq!(leap.iso8601_with_precision(3), "2016-12-31T23:59:60.250Z");
+    }
+
+    #[test]
+    fn test_add_and_subtract_weeks() {
+        let dt = DateTime::from_iso8601("2024-02-22T08:15:30.5Z").unwrap();
+
+        // Month rollover through a leap February, time of day preserved
+        let later = dt.add_weeks(1).unwrap();
+        assert_eq!(later.to_iso8601(), "2024-02-29T08:15:30.5Z");
+        let later = dt.add_weeks(2).unwrap();
+        assert_eq!(later.to_iso8601(), "2024-03-07T08:15:30.5Z");
+        assert_eq!(later.subtract_weeks(2).unwrap(), dt);
+
+        // Year rollover in both directions
+        let dec = DateTime::from_iso8601("2023-12-28T23:59:59Z").unwrap();
+        assert_eq!(dec.add_weeks(1).unwrap().to_iso8601(), "2024-01-04T23:59:59Z");
+        let jan = DateTime::from_iso8601("2024-01-03T00:00:00Z").unwrap();
+        assert_eq!(jan.subtract_weeks(1).unwrap().to_iso8601(), "2023-12-27T00:00:00Z");
+
+        assert_eq!(dt.add_weeks(0).unwrap(), dt);
+        assert_eq!(dt.add_weeks(52).unwrap().to_yyyy_mm_dd(), "2025-02-20");
+
+        // Leaving the supported range or overflowing the day count fails
+        assert!(DateTime::max_value().add_weeks(1).is_err());
+        assert!(DateTime::min_value().subtract_weeks(1).is_err());
+        assert!(dt.add_weeks(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors_are_combined() {
+        use crate::core::SharedError;
+        use crate::utils::UtilsError;
+        use crate::utils::time::DateTimeError;
+
+        // Each sub-parser fails for a different reason (day, month, shape...)
+        let err = DateTime::from("31/02/2024").unwrap_err();
+        match &err {
+            SharedError::Utils(UtilsError::DateTime(DateTimeError::UnparseableInput { input, attempts })) => {
+                assert_eq!(input, "31/02/2024");
+                let names: Vec<&str> = attempts.iter().map(|(name, _)| name.as_str()).collect();
+                assert_eq!(names, ["ISO8601", "YYYY-MM-DD", "DD/MM/YYYY", "MM/DD/YYYY", "YYYYMMDD",
+                    "DDMMYYYY", "MMDDYYYY", "YYMM", "YYYYMM", "YYYY-MM"]);
+                // Reasons carry the leaf message, not the error wrapping
+                assert!(attempts.iter().all(|(_, reason)| !reason.contains("Utils error")));
+            }
+            other => panic!("expected UnparseableInput, got {:?}", other),
+        }
+        let message = err.to_string();
+        assert!(message.contains("Cannot parse '31/02/2024' as a datetime"));
+        assert!(message.contains("DD/MM/YYYY ("));
+
+        // Inputs the old pre-check rejected now report the attempts too
+        let message = DateTime::from("").unwrap_err().to_string();
+        assert!(message.contains("tried: ISO8601"));
+
+        let err = DateTimeError::from_input_context("x", [("A", "bad"), ("B", "worse")]);
+        assert_eq!(err.to_string(), "Cannot parse 'x' as a datetime; tried: A (bad), B (worse)");
+        let err = DateTimeError::from_input_context("x", Vec::<(String, String)>::new());
+        assert_eq!(err.to_string(), "Cannot parse 'x' as a datetime; tried: no formats");
+    }
+
+    #[test]
+    fn test_slash_date_order_and_ambiguity() {
+        use crate::utils::time::DateOrder;
+
+        let day_first = DateTime::parse_slash_date("01/02/2024", DateOrder::DayFirst).unwrap();
+        let month_first = DateTime::parse_slash_date("01/02/2024", DateOrder::MonthFirst).unwrap();
+        assert_eq!(day_first.to_yyyy_mm_dd(), "2024-02-01");
+        assert_eq!(month_first.to_yyyy_mm_dd(), "2024-01-02");
+        assert_eq!(DateOrder::default(), DateOrder::DayFirst);
+
+        assert!(DateTime::parse_slash_date("15/03/2024", DateOrder::DayFirst).is_ok());
+        assert!(DateTime::parse_slash_date("15/03/2024", DateOrder::MonthFirst).is_err());
+
+        assert!(DateTime::is_ambiguous_slash_date("01/02/2024"));
+        assert!(DateTime::is_ambiguous_slash_date("12/11/1999"));
+        assert!(!DateTime::is_ambiguous_slash_date("15/03/2024"));
+        assert!(!DateTime::is_ambiguous_slash_date("03/15/2024"));
+        assert!(!DateTime::is_ambiguous_slash_date("05/05/2024"));
+        assert!(!DateTime::is_ambiguous_slash_date("2024-02-01"));
+        assert!(!DateTime::is_ambiguous_slash_date("31/02/2024"));
+    }
+
+    #[test]
+    fn test_extract_fields() {
+        let dt = DateTime::from_yyyy_mm_dd("2024-03-15").unwrap()
+            .with_hour(14).unwrap().with_minute(30).unwrap()
+            .with_second(45).unwrap().with_nanosecond(999_000_000).unwrap();
+
+        assert_eq!(dt.extract(DateField::Year), 2024);
+        assert_eq!(dt.extract(DateField::Quarter), 1);
+        assert_eq!(dt.extract(DateField::Month), 3);
+        assert_eq!(dt.extract(DateField::Week), 11);
+        assert_eq!(dt.extract(DateField::Day), 15);
+        assert_eq!(dt.extract(DateField::DayOfWeek), 5); // Friday
+        assert_eq!(dt.extract(DateField::DayOfYear), 75);
+        assert_eq!(dt.extract(DateField::Hour), 14);
+        assert_eq!(dt.extract(DateField::Minute), 30);
+        assert_eq!(dt.extract(DateField::Second), 45);
+        assert_eq!(dt.extract(DateField::Epoch), 1_710_513_045);
+        assert_eq!(dt.extract(DateField::Epoch), dt.to_timestamp().unwrap());
+
+        let sunday = DateTime::from_yyyy_mm_dd("2024-03-17").unwrap();
+        assert_eq!(sunday.extract(DateField::DayOfWeek), 0);
+        let before_epoch = DateTime::from_yyyy_mm_dd("1900-01-01").unwrap();
+        assert_eq!(before_epoch.extract(DateField::Epoch), -2_208_988_800);
+    }
+
+    #[test]
+    fn test_iso_week_boundaries() {
+        use chrono::Datelike;
+
+        // Jan 1st 2021 belongs to week 53 of 2020; Dec 30th 2024 to week 1 of 2025
+        assert_eq!(DateTime::from_yyyy_mm_dd("2021-01-01").unwrap().iso_week(), 53);
+        assert_eq!(DateTime::from_yyyy_mm_dd("2024-12-30").unwrap().iso_week(), 1);
+
+        // Cross-check weekday and ISO week against chrono over the whole supported range
+        let mut date = chrono::NaiveDate::from_ymd_opt(1900, 1, 1).unwrap();
+        let last = chrono::NaiveDate::from_ymd_opt(2100, 12, 31).unwrap();
+        while date <= last {
+            let dt = DateTime::from_chrono_naive(&date.and_hms_opt(0, 0, 0).unwrap()).unwrap();
+            assert_eq!(dt.weekday(), date.weekday(), "weekday of {}", date);
+            assert_eq!(dt.iso_week() as u32, date.iso_week().week(), "ISO week of {}", date);
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_timestamp_out_of_range() {
+        use crate::core::SharedError;
+        use crate::utils::UtilsError;
+        use crate::utils::time::DateTimeError;
+
+        assert!(DateTime::from_timestamp(-2_208_988_800).is_ok()); // 1900-01-01T00:00:00
+        let err = DateTime::from_timestamp(-2_208_988_801).unwrap_err();
+        match &err {
+            SharedError::Utils(UtilsError::DateTime(DateTimeError::TimestampOutOfRange { timestamp, year })) => {
+                assert_eq!(*timestamp, -2_208_988_801);
+                assert_eq!(*year, Some(1899));
+            }
+            other => panic!("expected TimestampOutOfRange, got {:?}", other),
+        }
+        assert!(err.to_string().contains("year 1899 is outside 1900-2100"));
+
+        // Every timestamp entry point reports the same error
+        let out_of_range = |result: crate::core::Result<DateTime>| matches!(
+            result,
+            Err(SharedError::Utils(UtilsError::DateTime(DateTimeError::TimestampOutOfRange { year: Some(2200), .. })))
+        );
+        assert!(out_of_range(DateTime::from_timestamp(7_258_118_400)));
+        assert!(out_of_range(DateTime::from_timestamp_nanos(7_258_118_400, 5)));
+        assert!(out_of_range(DateTime::from_unix_millis(7_258_118_400_000)));
+        assert!(out_of_range(DateTime::from_unix_micros(7_258_118_400_000_000)));
+
+        // Values chrono itself rejects are out of range too, without a year
+        let err = DateTime::from_timestamp(i64::MAX).unwrap_err();
+        assert!(matches!(
+            err,
+            SharedError::Utils(UtilsError::DateTime(DateTimeError::TimestampOutOfRange { timestamp: i64::MAX, year: None }))
+        ));
+        assert!(err.to_string().contains("no date in 1900-2100"));
+        assert!(matches!(
+            DateTime::from_timestamp_nanos(i64::MAX, 1_000_000_000),
+            Err(SharedError::Utils(UtilsError::DateTime(DateTimeError::TimestampOutOfRange { year: None, .. })))
+        ));
+    }
+
+    #[test]
+    fn test_saturating_duration_arithmetic() {
+        let dt = DateTime::from_yyyy_mm_dd("2024-03-15").unwrap();
+        let day = Duration::from_days(1);
+        assert_eq!(dt.add_duration_saturating(&day), dt.add_duration(&day).unwrap());
+        assert_eq!(dt.subtract_duration_saturating(&day), dt.subtract_duration(&day).unwrap());
+
+        // Overflow clamps instead of failing, even for durations that would overflow internally
+        let huge = Duration::from_nanos(u64::MAX);
+        assert_eq!(dt.add_duration_saturating(&huge), DateTime::max_value());
+        assert_eq!(dt.subtract_duration_saturating(&huge), DateTime::min_value());
+        let near_end = DateTime::from_yyyy_mm_dd("2100-12-31").unwrap();
+        assert_eq!(near_end.add_duration_saturating(&Duration::from_days(2)), DateTime::max_value());
+
+        // Landing exactly on the bounds is not clamping
+        let last_ns = DateTime::max_value().subtract_duration(&Duration::from_nanos(1)).unwrap();
+        assert_eq!(last_ns.add_duration_saturating(&Duration::from_nanos(1)), DateTime::max_value());
+    }
+
+    #[test]
+    fn test_clamp_to_bounds() {
+        let dt = DateTime::from_yyyy_mm_dd("2024-03-15").unwrap();
+        assert_eq!(dt.clamp_to_bounds(), dt);
+        assert_eq!(DateTime::max_value().clamp_to_bounds(), DateTime::max_value());
+
+        // Hand-built values past the end clamp back into range
+        let mut past_end = DateTime::max_value();
+        past_end.nanosecond = 1_999_999_999;
+        assert_eq!(past_end.clamp_to_bounds(), DateTime::max_value());
+    }
+
+    #[test]
+    fn test_datetime_readable_full_formatting() {
+        let year = Year::from_number(2024).unwrap();
+        let month = Month::from_number(3).unwrap();
+        let day = Day::from_number(15).unwrap();
+        let dt = DateTime::new(year, month, day, 14, 30, 45, 0).unwrap();
+
+        assert_eq!(dt.to_readable_full_en().unwrap(), "Friday, March 15, 2024 at 14:30:45");
+        assert_eq!(dt.to_readable_full_ptbr().unwrap(), "Sexta-feira, 15 de Março de 2024 às 14:30:45");
+
+        let sunday = DateTime::from_yyyy_mm_dd("2024-03-17").unwrap();
+        assert_eq!(sunday.to_readable_full_en().unwrap(), "Sunday, March 17, 2024 at 00:00:00");
+        assert_eq!(sunday.to_readable_full_ptbr().unwrap(), "Domingo, 17 de Março de 2024 às 00:00:00");
+    }
+
+    #[test]
+    fn test_month_boundary_predicates() {
+        let dt = |input: &str| DateTime::from_yyyy_mm_dd(input).unwrap();
+
+        assert!(dt("2024-03-01").is_first_day_of_month());
+        assert!(!dt("2024-03-02").is_first_day_of_month());
+
+        assert!(dt("2024-03-31").is_last_day_of_month());
+        assert!(dt("2024-04-30").is_last_day_of_month());
+        assert!(!dt("2024-03-30").is_last_day_of_month());
+        assert!(dt("2024-02-29").is_last_day_of_month());
+        assert!(!dt("2024-02-28").is_last_day_of_month());
+        assert!(dt("2023-02-28").is_last_day_of_month());
+
+        assert!(dt("2024-02-29").is_leap_day());
+        assert!(!dt("2023-02-28").is_leap_day());
+        assert!(!dt("2024-03-29").is_leap_day());
+    }
+
+    #[test]
+    fn test_error_source_chain() {
+        use std::error::Error;
+        use crate::core::SharedError;
+        use crate::utils::UtilsError;
+        use crate::utils::time::DateTimeError;
+
+        // SharedError -> UtilsError -> DateTimeError (leaf)
+        let err = DateTime::from_timestamp(i64::MAX).unwrap_err();
+        assert!(matches!(err, SharedError::Utils(UtilsError::DateTime(_))));
+
+        let utils = err.source().expect("SharedError::Utils should expose its UtilsError");
+        assert!(utils.downcast_ref::<UtilsError>().is_some());
+
+        let leaf = utils.source().expect("UtilsError::DateTime should expose its DateTimeError");
+        let datetime_err = leaf.downcast_ref::<DateTimeError>().expect("leaf should be a DateTimeError");
+        assert!(matches!(datetime_err, DateTimeError::TimestampOutOfRange { year: None, .. }));
+        assert!(leaf.source().is_none());
+
+        // The same walk reaches the leaf for other utils errors
+        let chain: Vec<String> = std::iter::successors(
+            Some(&Year::from_number(1850).unwrap_err() as &dyn Error),
+            |e| e.source(),
+        ).map(|e| e.to_string()).collect();
+        assert_eq!(chain.len(), 3);
+        assert!(chain[2].contains("1850"));
+    }
+
+    #[test]
+    fn test_datetime_parser_cache() {
+        use crate::utils::time::DateTimeParser;
+
+        let mut parser = DateTimeParser::with_capacity(2);
+        assert!(parser.is_empty());
+
+        let first = parser.parse("20240315", DateTimeFormat::YYYYMMDD).unwrap();
+        assert_eq!(first, DateTime::from_yyyymmdd("20240315").unwrap());
+        assert_eq!(parser.parse("20240315", DateTimeFormat::YYYYMMDD).unwrap(), first);
+        assert_eq!(parser.len(), 1);
+
+        // The same string under another format is a separate entry
+        assert_eq!(parser.parse("202403", DateTimeFormat::YYYYMM).unwrap(), DateTime::from_yyyymm("202403").unwrap());
+        assert_eq!(parser.len(), 2);
+
+        // Failures are returned and not cached
+        assert!(parser.parse("20241315", DateTimeFormat::YYYYMMDD).is_err());
+        assert_eq!(parser.len(), 2);
+
+        // A full cache is cleared before the next insert
+        parser.parse("20240101", DateTimeFormat::YYYYMMDD).unwrap();
+        assert_eq!(parser.len(), 1);
+        assert!(parser.len() <= parser.capacity());
+
+        // Capacity 0 parses without caching
+        let mut uncached = DateTimeParser::with_capacity(0);
+        assert_eq!(uncached.parse("20240315", DateTimeFormat::YYYYMMDD).unwrap(), first);
+        assert!(uncached.is_empty());
+        assert_eq!(DateTimeParser::default().capacity(), DateTimeParser::DEFAULT_CAPACITY);
+    }
+
+    #[test]
+    fn test_component_tuple_round_trip() {
+        let dt = DateTime::new(
+            Year::from_number(2024).unwrap(), Month::from_number(2).unwrap(), Day::from_number(29).unwrap(),
+            14, 30, 45, 123_456_789,
+        ).unwrap();
+
+        let parts: (i32, u8, u8, u8, u8, u8, u32) = dt.clone().into();
+        assert_eq!(parts, (2024, 2, 29, 14, 30, 45, 123_456_789));
+        assert_eq!(DateTime::try_from(parts).unwrap(), dt);
+
+        // Every component is validated
+        assert!(DateTime::try_from((1850, 1, 1, 0, 0, 0, 0)).is_err());
+        assert!(DateTime::try_from((2024, 13, 1, 0, 0, 0, 0)).is_err());
+        assert!(DateTime::try_from((2023, 2, 29, 0, 0, 0, 0)).is_err());
+        assert!(DateTime::try_from((2024, 1, 1, 24, 0, 0, 0)).is_err());
+        assert!(DateTime::try_from((2024, 1, 1, 0, 60, 0, 0)).is_err());
+        assert!(DateTime::try_from((2024, 1, 1, 0, 0, 0, 2_000_000_000)).is_err());
+    }
+
+    #[test]
+    fn test_with_time_of_day() {
+        let dt = DateTime::from_yyyy_mm_dd("2024-03-15").unwrap().with_hour(9).unwrap();
+
+        let time = Duration::from_components(14, 30, 45, 123, 456);
+        let updated = dt.with_time_of_day(&time).unwrap();
+        assert_eq!((updated.hour(), updated.minute(), updated.second()), (14, 30, 45));
+        assert_eq!(updated.nanosecond(), 123_000_456);
+        assert_eq!(updated.date_part(), dt.date_part());
+        assert_eq!(updated.time_since_midnight(), time);
+
+        let last = Duration::from_hours(24).saturating_sub(&Duration::from_nanos(1));
+        assert!(dt.with_time_of_day(&last).is_ok());
+        assert!(dt.with_time_of_day(&Duration::from_hours(24)).is_err());
+        assert!(dt.with_time_of_day(&Duration::from_hours(30)).is_err());
+
+        assert_eq!(dt.set_midnight().unwrap(), DateTime::from_yyyy_mm_dd("2024-03-15").unwrap());
+        let noon = dt.set_noon().unwrap();
+        assert_eq!((noon.hour(), noon.minute(), noon.second(), noon.nanosecond()), (12, 0, 0, 0));
+        assert_eq!(noon.date_part(), dt.date_part());
+    }
+
+    #[test]
+    fn test_from_timestamp_nanos_carries_whole_seconds() {
+        let ts = 1_710_513_045; // 2024-03-15T14:30:45Z
+
+        let one_second = DateTime::from_timestamp_nanos(ts, 1_000_000_000).unwrap();
+        assert_eq!(one_second, DateTime::from_timestamp(ts + 1).unwrap());
+        assert_eq!(one_second.nanosecond(), 0);
+        assert!(!one_second.is_leap_second());
+
+        let two_and_half = DateTime::from_timestamp_nanos(ts, 2_500_000_000).unwrap();
+        assert_eq!(two_and_half, DateTime::from_timestamp_nanos(ts + 2, 500_000_000).unwrap());
+        assert_eq!((two_and_half.second(), two_and_half.nanosecond()), (47, 500_000_000));
+
+        // At :59 the carry rolls into the next minute instead of encoding a leap second
+        let minute_end = DateTime::from_timestamp_nanos(ts + 14, 1_500_000_000).unwrap();
+        assert_eq!((minute_end.minute(), minute_end.second(), minute_end.nanosecond()), (31, 0, 500_000_000));
+
+        // Carrying past i64::MAX is a conversion error, not a panic
+        assert!(DateTime::from_timestamp_nanos(i64::MAX, 1_000_000_000).is_err());
+    }
+
+    #[test]
+    fn test_format_into_reuses_buffer() {
+        let dt = DateTime::from_yyyy_mm_dd("2024-03-15").unwrap()
+            .with_hour(14).unwrap().with_nanosecond(120_000_000).unwrap();
+
+        let mut buf = String::new();
+        dt.format_into(&DateTimeFormat::YYYYMMDD, &mut buf).unwrap();
+        buf.push(';');
+        dt.format_into(&DateTimeFormat::DD_MM_YYYY, &mut buf).unwrap();
+        buf.push(';');
+        dt.format_into(&DateTimeFormat::ISO8601, &mut buf).unwrap();
+        assert_eq!(buf, "20240315;15/03/2024;2024-03-15T14:00:00.12Z");
+
+        // Every format matches to_format
+        let formats = [
+            DateTimeFormat::ISO8601, DateTimeFormat::YYYYMMDD, DateTimeFormat::YYYY_MM_DD,
+            DateTimeFormat::DD_MM_YYYY, DateTimeFormat::MM_DD_YYYY, DateTimeFormat::DDMMYYYY,
+            DateTimeFormat::MMDDYYYY, DateTimeFormat::YYMM, DateTimeFormat::YYYYMM,
+            DateTimeFormat::YYYY_MM, DateTimeFormat::Custom("%d.%m.%Y %H:%M".to_string()),
+        ];
+        for format in formats {
+            buf.clear();
+            dt.format_into(&format, &mut buf).unwrap();
+            assert_eq!(buf, dt.to_format(format).unwrap());
+        }
+
+        // A bad custom pattern fails without leaving partial output
+        buf.clear();
+        buf.push_str("row:");
+        assert!(dt.format_into(&DateTimeFormat::Custom("%Y-%Q".to_string()), &mut buf).is_err());
+        assert_eq!(buf, "row:");
+
+        // ISO8601 trims trailing zeros and omits a zero fraction
+        assert_eq!(dt.with_nanosecond(0).unwrap().to_iso8601(), "2024-03-15T14:00:00Z");
+        assert_eq!(dt.with_nanosecond(1).unwrap().to_iso8601(), "2024-03-15T14:00:00.000000001Z");
+    }
+
+    #[test]
+    fn test_eq_to_granularity() {
+        let base = DateTime::from_yyyy_mm_dd("2024-03-15").unwrap()
+            .with_hour(14).unwrap().with_minute(30).unwrap().with_second(45).unwrap();
+        let precise = base.with_nanosecond(999_999_999).unwrap();
+
+        assert_ne!(base, precise);
+        assert!(base.eq_to_second(&precise));
+        assert!(base.eq_to_minute(&precise));
+        assert!(base.eq_to_day(&precise));
+
+        // Calendar boundaries matter, not the distance between the values
+        let next_second = precise.add_duration(&Duration::from_nanos(1)).unwrap();
+        assert!(!precise.eq_to_second(&next_second));
+        assert!(precise.eq_to_minute(&next_second));
+
+        let other_minute = base.with_minute(31).unwrap();
+        assert!(!base.eq_to_minute(&other_minute));
+        assert!(base.eq_to_day(&other_minute));
+
+        let midnight = DateTime::from_yyyy_mm_dd("2024-03-16").unwrap();
+        let just_before = midnight.subtract_duration(&Duration::from_nanos(1)).unwrap();
+        assert!(!midnight.eq_to_day(&just_before));
+        assert!(just_before.eq_to_day(&base));
+    }
+
+    #[test]
+    fn test_from_numeric_timestamps() {
+        use crate::core::SharedError;
+        use crate::utils::UtilsError;
+        use crate::utils::time::DateTimeError;
+
+        assert_eq!(DateTime::from(1_710_513_045i64).unwrap(), DateTime::from_timestamp(1_710_513_045).unwrap());
+        assert_eq!(
+            DateTime::from((1_710_513_045i64, 250_000_000u32)).unwrap(),
+            DateTime::from_timestamp_nanos(1_710_513_045, 250_000_000).unwrap()
+        );
+
+        // The whole supported range works, including before 1970 and late 2100
+        let moon_landing = DateTime::from(-14_182_980i64).unwrap();
+        assert_eq!(moon_landing.to_yyyy_mm_dd(), "1969-07-20");
+        assert_eq!(DateTime::from(4_133_980_799i64).unwrap(), DateTime::max_value().truncate_to_second().unwrap());
+        assert!(DateTime::is_valid(4_115_491_200i64)); // 2100-06-01
+        assert!(DateTime::is_valid(-2_208_988_800i64)); // 1900-01-01
+
+        // Outside the range, the timestamp constructors' error comes through unchanged
+        assert!(!DateTime::is_valid(4_133_980_800i64));
+        assert!(matches!(
+            DateTime::from(4_133_980_800i64),
+            Err(SharedError::Utils(UtilsError::DateTime(DateTimeError::TimestampOutOfRange { year: Some(2101), .. })))
+        ));
+        assert!(matches!(
+            DateTime::from((-2_208_988_801i64, 0u32)),
+            Err(SharedError::Utils(UtilsError::DateTime(DateTimeError::TimestampOutOfRange { year: Some(1899), .. })))
+        ));
+    }
 }
\ No newline at end of file
@@ -34,8 +34,8 @@ impl DateTimeValidatable for String {
 
 impl DateTimeValidatable for i64 {
     fn is_valid_datetime(&self) -> bool {
-        // Basic timestamp validation - reasonable range
-        *self >= 0 && *self <= 4_102_444_800 // Up to year 2100
+        // Unix timestamp in seconds within the supported years (1900-2100)
+        DateTime::from_timestamp(*self).is_ok()
     }
 }
 
@@ -46,51 +46,8 @@ pub trait DateTimeFromInput {
 
 impl DateTimeFromInput for &str {
     fn parse_datetime(self) -> Result<DateTime> {
-        if !self.is_valid_datetime() {
-            return Err(UtilsError::DateTime(
-                DateTimeError::cannot_parse_datetime(format!("Unable to parse '{}' as a datetime", self))
-            ).into());
-        }
-        
-        // Try common formats in order of likelihood
-        if let Ok(dt) = DateTime::from_iso8601(self) {
-            return Ok(dt);
-        }
-        
-        if let Ok(dt) = DateTime::from_yyyy_mm_dd(self) {
-            return Ok(dt);
-        }
-        
-        if let Ok(dt) = DateTime::from_dd_mm_yyyy(self) {
-            return Ok(dt);
-        }
-        
-        if let Ok(dt) = DateTime::from_mm_dd_yyyy(self) {
-            return Ok(dt);
-        }
-        
-        if let Ok(dt) = DateTime::from_yyyymmdd(self) {
-            return Ok(dt);
-        }
-        
-        if let Ok(dt) = DateTime::from_ddmmyyyy(self) {
-            return Ok(dt);
-        }
-        
-        if let Ok(dt) = DateTime::from_mmddyyyy(self) {
-            return Ok(dt);
-        }
-        
-        // Try YYMM format (4 digits, all numeric)
-        if self.len() == 4 && self.chars().all(|c| c.is_ascii_digit()) {
-            if let Ok(dt) = DateTime::from_yymm(self) {
-                return Ok(dt);
-            }
-        }
-        
-        Err(UtilsError::DateTime(
-            DateTimeError::cannot_parse_datetime(format!("Unable to parse '{}' as a datetime", self))
-        ).into())
+        // Try every known format in priority order; the error lists each attempt
+        DateTime::parse_any(self)
     }
 }
 
@@ -106,25 +63,17 @@ impl DateTimeFromInput for &String {
     }
 }
 
+/// Unix timestamp in seconds; out-of-range values fail with `TimestampOutOfRange`
 impl DateTimeFromInput for i64 {
     fn parse_datetime(self) -> Result<DateTime> {
-        if !self.is_valid_datetime() {
-            return Err(UtilsError::DateTime(
-                DateTimeError::timestamp_conversion(format!("Invalid timestamp: {}", self))
-            ).into());
-        }
         DateTime::from_timestamp(self)
     }
 }
 
+/// (Unix seconds, nanoseconds); see `DateTime::from_timestamp_nanos` for the carry rules
 impl DateTimeFromInput for (i64, u32) {
     fn parse_datetime(self) -> Result<DateTime> {
         let (secs, nanos) = self;
-        if !secs.is_valid_datetime() {
-            return Err(UtilsError::DateTime(
-                DateTimeError::timestamp_conversion(format!("Invalid timestamp: {}", secs))
-            ).into());
-        }
         DateTime::from_timestamp_nanos(secs, nanos)
     }
 }
@@ -0,0 +1,126 @@
+use std::fmt::Write;
+use chrono::{DateTime as ChronoDateTime, FixedOffset, LocalResult, Offset, TimeZone};
+use crate::core::Result;
+use crate::utils::{UtilsError};
+use crate::utils::time::datetime::{DateTime, DateTimeError};
+
+/// ZonedDateTime pairs a local wall-clock DateTime with its UTC offset
+///
+/// The plain `DateTime` stays naive/UTC; use this type when the offset
+/// matters, e.g. São Paulo local times (-03:00).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZonedDateTime {
+    local: DateTime,
+    offset: FixedOffset,
+}
+
+impl ZonedDateTime {
+    // === Constructors ===
+
+    /// Create from a local wall-clock DateTime and its offset
+    pub fn new(local: DateTime, offset: FixedOffset) -> Self {
+        Self { local, offset }
+    }
+
+    /// Create from a UTC DateTime, converting it to local time at the given offset
+    pub fn from_utc(utc: &DateTime, offset: FixedOffset) -> Result<Self> {
+        let chrono_dt = utc.to_chrono_utc()?.with_timezone(&offset);
+        let local = DateTime::from_chrono_naive(&chrono_dt.naive_local())?;
+        Ok(Self { local, offset })
+    }
+
+    /// Create from a local wall-clock DateTime in any chrono timezone
+    ///
+    /// Local times that are ambiguous (DST fall-back) or nonexistent
+    /// (DST spring-forward gap) in `tz` are rejected with an error.
+    pub fn from_local_in_timezone<Tz: TimeZone>(local: &DateTime, tz: &Tz) -> Result<Self> {
+        let naive = local.to_chrono_naive()?;
+        match tz.from_local_datetime(&naive) {
+            LocalResult::Single(dt) => Ok(Self { local: local.clone(), offset: dt.offset().fix() }),
+            LocalResult::Ambiguous(earliest, latest) => Err(UtilsError::DateTime(
+                DateTimeError::invalid_timezone(format!(
+                    "Local time {} is ambiguous (offset {} or {})",
+                    naive, earliest.offset().fix(), latest.offset().fix()
+                ))
+            ).into()),
+            LocalResult::None => Err(UtilsError::DateTime(
+                DateTimeError::invalid_timezone(format!("Local time {} does not exist in the target timezone", naive))
+            ).into()),
+        }
+    }
+
+    /// Parse ISO8601/RFC3339 with an offset: "2024-03-15T14:30:45-03:00" or "2024-03-15T17:30:45Z"
+    pub fn from_iso8601(input: &str) -> Result<Self> {
+        let chrono_dt = ChronoDateTime::parse_from_rfc3339(input)
+            .map_err(|e| UtilsError::DateTime(
+                DateTimeError::cannot_parse_datetime(format!("Invalid ISO8601 datetime with offset '{}': {}", input, e))
+            ))?;
+        Self::from_chrono(&chrono_dt)
+    }
+
+    /// Create from a chrono DateTime with a fixed offset
+    pub fn from_chrono(dt: &ChronoDateTime<FixedOffset>) -> Result<Self> {
+        let local = DateTime::from_chrono_naive(&dt.naive_local())?;
+        Ok(Self { local, offset: *dt.offset() })
+    }
+
+    // === Accessors ===
+
+    /// Get the local wall-clock DateTime
+    pub fn local(&self) -> &DateTime {
+        &self.local
+    }
+
+    /// Get the UTC offset
+    pub fn offset(&self) -> FixedOffset {
+        self.offset
+    }
+
+    // === Conversion ===
+
+    /// Convert to the equivalent UTC DateTime
+    pub fn to_utc(&self) -> Result<DateTime> {
+        let chrono_dt = self.to_chrono()?;
+        DateTime::from_chrono_naive(&chrono_dt.naive_utc())
+    }
+
+    /// Express the same instant at a different offset
+    pub fn with_timezone(&self, offset: FixedOffset) -> Result<Self> {
+        let chrono_dt = self.to_chrono()?.with_timezone(&offset);
+        Self::from_chrono(&chrono_dt)
+    }
+
+    /// Convert to a chrono DateTime with a fixed offset
+    pub fn to_chrono(&self) -> Result<ChronoDateTime<FixedOffset>> {
+        let naive = self.local.to_chrono_naive()?;
+        self.offset.from_local_datetime(&naive)
+            .single()
+            .ok_or_else(|| UtilsError::DateTime(
+                DateTimeError::chrono_conversion(format!("Cannot apply offset {} to {}", self.offset, naive))
+            ).into())
+    }
+
+    /// Format as minimal ISO8601 with the offset: "2024-03-15T14:30:45-03:00"
+    ///
+    /// The fraction follows `DateTime::to_iso8601`: omitted when zero and
+    /// trailing zeros trimmed otherwise.
+    pub fn to_iso8601(&self) -> String {
+        self.to_string()
+    }
+
+    /// Format as ISO8601 with all nine fractional digits: "2024-03-15T14:30:45.000000000-03:00"
+    pub fn to_iso8601_nanos(&self) -> String {
+        let mut out = String::with_capacity(35);
+        // Writing into a String cannot fail
+        let _ = self.local.write_iso8601_nanos_local(&mut out).and_then(|_| write!(out, "{}", self.offset));
+        out
+    }
+}
+
+// === Display implementation ===
+impl std::fmt::Display for ZonedDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.local.write_iso8601_local(f)?;
+        write!(f, "{}", self.offset)
+    }
+}
@@ -1,10 +1,11 @@
-use dashmap::DashSet;
 use std::sync::LazyLock;
-use serde::{Serialize, Deserialize};
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Visitor;
 use chrono::{NaiveDate, DateTime, Weekday, Datelike, TimeZone};
-use crate::core::Result;
+use crate::core::{Result, SharedError};
 use crate::utils::{UtilsError};
 use crate::utils::time::{Month, Year};
+use crate::utils::time::component_serde::{self, NumberedComponent};
 
 pub mod error;
 pub mod traits;
@@ -14,7 +15,8 @@ mod tests;
 pub use error::DayError;
 pub use traits::{DayValidatable, DayFromInput};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Serialize, Deserialize)]
+/// Ordering follows the numeric `day` field; the other fields are derived from it
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 pub struct Day {
     pub day: u8,
     pub text: &'static str,
@@ -23,14 +25,6 @@ pub struct Day {
 }
 
 // Static collections following existing pattern
-pub static DAYS: LazyLock<DashSet<Day>> = LazyLock::new(|| {
-    let days = DashSet::with_capacity(31);
-    (1..=31).for_each(|i| {
-        days.insert(Day::new_unchecked(i));
-    });
-    days
-});
-
 pub static DAYS_ORDERED: LazyLock<[Day; 31]> = LazyLock::new(|| {
     (1..=31)
         .map(Day::new_unchecked)
@@ -97,6 +91,18 @@ impl Day {
         }
     }
     
+    /// Find day by ordinal ("1st", "22nd", "31st" or pt-BR "2º"), case-insensitive
+    pub fn from_ordinal(ordinal: &str) -> Result<Day> {
+        Self::ORDINAL_EN
+            .iter()
+            .position(|&en| en.eq_ignore_ascii_case(ordinal))
+            .or_else(|| Self::ORDINAL_PTBR.iter().position(|&ptbr| ptbr == ordinal))
+            .map(|index| Self::all_days()[index])
+            .ok_or_else(|| UtilsError::Day(
+                DayError::cannot_parse_day(format!("Unable to parse '{}' as an ordinal day", ordinal))
+            ).into())
+    }
+    
     /// Check if this day is valid for a specific month and year
     pub fn is_valid_for_month(&self, month: &Month, year: &Year) -> bool {
         year.is_valid_date(month, self.day as u32)
@@ -116,6 +122,15 @@ impl Day {
         }
     }
     
+    /// Iterate over every valid day of a month in a specific year
+    ///
+    /// Stops at the real last day of the month (e.g. Feb 2023 ends at 28,
+    /// Feb 2024 at 29).
+    pub fn iter_in_month(month: &Month, year: &Year) -> impl DoubleEndedIterator<Item = Day> + ExactSizeIterator + use<> {
+        let last_day = year.days_in_month(month) as usize;
+        Self::all_days()[..last_day].iter().copied()
+    }
+    
     /// Create a NaiveDate from this day with month and year
     pub fn to_naive_date(&self, month: &Month, year: &Year) -> Result<NaiveDate> {
         year.to_naive_date(month, self.day as u32)
@@ -147,23 +162,19 @@ impl Day {
 
     /// Get localized weekday name
     pub fn to_weekday_name_en(&self, month: &Month, year: &Year) -> Result<&'static str> {
-        let weekday = self.to_weekday(month, year)?;
-        Ok(Self::WEEKDAY_NAMES_EN[weekday.num_days_from_monday() as usize])
+        Ok(weekday_name_en(self.to_weekday(month, year)?))
     }
     
     pub fn to_weekday_name_ptbr(&self, month: &Month, year: &Year) -> Result<&'static str> {
-        let weekday = self.to_weekday(month, year)?;
-        Ok(Self::WEEKDAY_NAMES_PTBR[weekday.num_days_from_monday() as usize])
+        Ok(weekday_name_ptbr(self.to_weekday(month, year)?))
     }
     
     pub fn to_weekday_short_en(&self, month: &Month, year: &Year) -> Result<&'static str> {
-        let weekday = self.to_weekday(month, year)?;
-        Ok(Self::WEEKDAY_SHORT_EN[weekday.num_days_from_monday() as usize])
+        Ok(weekday_short_en(self.to_weekday(month, year)?))
     }
     
     pub fn to_weekday_short_ptbr(&self, month: &Month, year: &Year) -> Result<&'static str> {
-        let weekday = self.to_weekday(month, year)?;
-        Ok(Self::WEEKDAY_SHORT_PTBR[weekday.num_days_from_monday() as usize])
+        Ok(weekday_short_ptbr(self.to_weekday(month, year)?))
     }
     
     /// Navigation methods
@@ -276,10 +287,121 @@ impl Day {
         day >= 1 && day <= 31
     }
     
+    pub fn is_valid_ordinal(input: &str) -> bool {
+        Self::ORDINAL_EN.iter().any(|en| en.eq_ignore_ascii_case(input))
+            || Self::ORDINAL_PTBR.contains(&input)
+    }
+    
     pub fn is_valid_day_string(input: &str) -> bool {
         match input.parse::<u8>() {
             Ok(num) => Self::is_valid_day_number(num),
             Err(_) => false,
         }
     }
-}
\ No newline at end of file
+}
+// === Weekday names ===
+// Lookups for a weekday that is already known, without needing a date
+
+/// English weekday name: "Friday"
+pub fn weekday_name_en(weekday: Weekday) -> &'static str {
+    Day::WEEKDAY_NAMES_EN[weekday.num_days_from_monday() as usize]
+}
+
+/// Brazilian Portuguese weekday name: "Sexta-feira"
+pub fn weekday_name_ptbr(weekday: Weekday) -> &'static str {
+    Day::WEEKDAY_NAMES_PTBR[weekday.num_days_from_monday() as usize]
+}
+
+/// Abbreviated English weekday name: "Fri"
+pub fn weekday_short_en(weekday: Weekday) -> &'static str {
+    Day::WEEKDAY_SHORT_EN[weekday.num_days_from_monday() as usize]
+}
+
+/// Abbreviated Brazilian Portuguese weekday name: "Sex"
+pub fn weekday_short_ptbr(weekday: Weekday) -> &'static str {
+    Day::WEEKDAY_SHORT_PTBR[weekday.num_days_from_monday() as usize]
+}
+
+// === Display / FromStr implementations ===
+impl std::fmt::Display for Day {
+    /// "15" by default, "15th" with the alternate flag (`{:#}`)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.alternate() {
+            true => write!(f, "{}", self.ordinal_en),
+            false => write!(f, "{}", self.day),
+        }
+    }
+}
+
+impl std::str::FromStr for Day {
+    type Err = SharedError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Day::from(s)
+    }
+}
+
+// === TryFrom implementations ===
+impl TryFrom<u8> for Day {
+    type Error = SharedError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Day::from(value)
+    }
+}
+
+impl TryFrom<&str> for Day {
+    type Error = SharedError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Day::from(value)
+    }
+}
+
+impl TryFrom<String> for Day {
+    type Error = SharedError;
+
+    fn try_from(value: String) -> Result<Self> {
+        Day::from(value)
+    }
+}
+
+// === Serde implementations ===
+impl Serialize for Day {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.day)
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        component_serde::deserialize(deserializer)
+    }
+}
+
+impl NumberedComponent for Day {
+    const FIELD: &'static str = "day";
+
+    fn expecting(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a day between 1 and 31 as an integer, a string, or an object with a `day` field")
+    }
+
+    fn from_wire_number(value: i64) -> Result<Self> {
+        match u8::try_from(value) {
+            Ok(number) => Day::from(number),
+            Err(_) => Err(UtilsError::Day(DayError::not_valid_day(value.to_string())).into()),
+        }
+    }
+
+    fn from_wire_str(value: &str) -> Result<Self> {
+        Day::from(value)
+    }
+
+    fn deserialize_number<'de, D, V>(deserializer: D, visitor: V) -> std::result::Result<V::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Visitor<'de>,
+    {
+        deserializer.deserialize_u8(visitor)
+    }
+}
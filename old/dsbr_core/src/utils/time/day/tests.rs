@@ -409,4 +409,136 @@ mod tests {
             assert_eq!(extracted_day.day, day_num);
         }
     }
+
+    #[test]
+    fn test_iter_in_month() {
+        let feb = Month::from_number(2).unwrap();
+        let year_2023 = Year::from_number(2023).unwrap();
+        let year_2024 = Year::from_number(2024).unwrap();
+
+        let days: Vec<u8> = Day::iter_in_month(&feb, &year_2023).map(|d| d.day).collect();
+        assert_eq!(days.len(), 28);
+        assert_eq!(days.first(), Some(&1));
+        assert_eq!(days.last(), Some(&28));
+
+        assert_eq!(Day::iter_in_month(&feb, &year_2024).count(), 29);
+        assert_eq!(Day::iter_in_month(&Month::from_number(4).unwrap(), &year_2024).count(), 30);
+        assert_eq!(Day::iter_in_month(&Month::from_number(12).unwrap(), &year_2024).count(), 31);
+
+        // Double-ended: iterate backwards from the last valid day
+        let last = Day::iter_in_month(&feb, &year_2024).next_back().unwrap();
+        assert_eq!(last.day, 29);
+
+        // Year convenience
+        assert_eq!(year_2023.iter_days_in_month(&feb).count(), 28);
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let day = Day::from_number(15).unwrap();
+        assert_eq!(day.to_string(), "15");
+        assert_eq!(format!("{:#}", day), "15th");
+        assert_eq!(Day::from_number(3).unwrap().to_string(), "3");
+
+        let parsed: Day = "15".parse().unwrap();
+        assert_eq!(parsed, day);
+        assert!("32".parse::<Day>().is_err());
+    }
+
+    #[test]
+    fn test_from_ordinal_strings() {
+        assert_eq!(Day::from("31st").unwrap().day, 31);
+        assert_eq!(Day::from("22nd").unwrap().day, 22);
+        assert_eq!(Day::from("3rd").unwrap().day, 3);
+        assert_eq!(Day::from("1ST").unwrap().day, 1);
+        assert_eq!(Day::from("2º").unwrap().day, 2);
+        assert_eq!(Day::from("21º").unwrap().day, 21);
+
+        // Numeric parsing still takes priority
+        assert_eq!(Day::from("15").unwrap().day, 15);
+
+        assert!(Day::from("32nd").is_err());
+        assert!(Day::from("2st").is_err());
+        assert!(Day::from("32º").is_err());
+
+        assert!(Day::is_valid("22nd"));
+        assert!(!Day::is_valid("32nd"));
+        assert_eq!(Day::from_ordinal("11th").unwrap().day, 11);
+    }
+
+    #[test]
+    fn test_try_from_conversions() {
+        let day: Day = 15u8.try_into().unwrap();
+        assert_eq!(day.day, 15);
+        assert_eq!(Day::try_from("15").unwrap(), day);
+        assert_eq!(Day::try_from("15th".to_string()).unwrap(), day);
+
+        assert!(Day::try_from(0u8).is_err());
+        assert!(Day::try_from("32").is_err());
+    }
+
+    #[test]
+    fn test_day_ordering() {
+        let ninth = Day::from_number(9).unwrap();
+        let tenth = Day::from_number(10).unwrap();
+        // Numeric order, not the order of the "09"/"10" text
+        assert!(ninth < tenth);
+
+        let mut days = vec![Day::from_number(31).unwrap(), tenth, Day::from_number(2).unwrap(), ninth];
+        days.sort();
+        let numbers: Vec<u8> = days.iter().map(|d| d.day).collect();
+        assert_eq!(numbers, vec![2, 9, 10, 31]);
+    }
+
+    #[test]
+    fn test_deserialize_validates() {
+        let day: Day = serde_json::from_str("15").unwrap();
+        assert_eq!(day, Day::from_number(15).unwrap());
+        let day: Day = serde_json::from_str(r#""05""#).unwrap();
+        assert_eq!(day.day, 5);
+        let day: Day = serde_json::from_str(r#""21st""#).unwrap();
+        assert_eq!(day.day, 21);
+
+        assert!(serde_json::from_str::<Day>("0").is_err());
+        assert!(serde_json::from_str::<Day>("32").is_err());
+        assert!(serde_json::from_str::<Day>("-1").is_err());
+
+        // Objects are rebuilt from the `day` field; inconsistent texts are ignored
+        let day: Day = serde_json::from_str(r#"{"day": 3, "ordinal_en": "9th"}"#).unwrap();
+        assert_eq!(day.ordinal_en, "3rd");
+        assert!(serde_json::from_str::<Day>(r#"{"day": 40, "text": "40"}"#).is_err());
+        assert!(serde_json::from_str::<Day>(r#"{"text": "05"}"#).is_err());
+
+        let original = Day::from_number(28).unwrap();
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(serde_json::from_str::<Day>(&json).unwrap(), original);
+    }
+
+    #[test]
+    fn test_serialize_compact() {
+        let day = Day::from_number(5).unwrap();
+        assert_eq!(serde_json::to_string(&day).unwrap(), "5");
+        assert_eq!(serde_json::from_str::<Day>(&serde_json::to_string(&day).unwrap()).unwrap(), day);
+    }
+
+    #[test]
+    fn test_standalone_weekday_names() {
+        use chrono::Weekday;
+        use crate::utils::time::{weekday_name_en, weekday_name_ptbr, weekday_short_en, weekday_short_ptbr};
+
+        assert_eq!(weekday_name_en(Weekday::Fri), "Friday");
+        assert_eq!(weekday_name_ptbr(Weekday::Fri), "Sexta-feira");
+        assert_eq!(weekday_short_en(Weekday::Sun), "Sun");
+        assert_eq!(weekday_short_ptbr(Weekday::Sat), "Sáb");
+
+        // Matches the date-based lookups
+        let day = Day::from_number(15).unwrap();
+        let month = Month::from_number(3).unwrap();
+        let year = Year::from_number(2024).unwrap();
+        let weekday = day.to_weekday(&month, &year).unwrap();
+        assert_eq!(day.to_weekday_name_en(&month, &year).unwrap(), weekday_name_en(weekday));
+        assert_eq!(day.to_weekday_name_ptbr(&month, &year).unwrap(), weekday_name_ptbr(weekday));
+        assert_eq!(day.to_weekday_short_en(&month, &year).unwrap(), weekday_short_en(weekday));
+        assert_eq!(day.to_weekday_short_ptbr(&month, &year).unwrap(), weekday_short_ptbr(weekday));
+    }
 }
\ No newline at end of file
@@ -15,7 +15,7 @@ impl DayValidatable for u8 {
 
 impl DayValidatable for str {
     fn is_valid_day(&self) -> bool {
-        Day::is_valid_day_string(self)
+        Day::is_valid_day_string(self) || Day::is_valid_ordinal(self)
     }
 }
 
@@ -55,12 +55,10 @@ impl DayFromInput for &str {
             ).into());
         }
         
-        if let Ok(num) = self.parse::<u8>() {
-            Day::from_number(num)
-        } else {
-            Err(UtilsError::Day(
-                DayError::cannot_parse_day(format!("Unable to parse '{}' as a day", self))
-            ).into())
+        // Plain numbers first, then ordinals ("21st", "3º")
+        match self.parse::<u8>() {
+            Ok(num) => Day::from_number(num),
+            Err(_) => Day::from_ordinal(self),
         }
     }
 }
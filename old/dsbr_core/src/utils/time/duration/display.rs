@@ -0,0 +1,42 @@
+use std::fmt;
+use crate::utils::time::duration::Duration;
+
+/// Output style for a borrowed `DurationDisplay`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DurationStyle {
+    #[default]
+    Readable,   // 2h 30m 45s
+    Hms,        // 02:30:45
+    Precise,    // 02:30:45.000000000
+    Iso8601,    // PT2H30M45S
+}
+
+/// Display adapter that formats a borrowed Duration in a chosen style
+///
+/// Created with `Duration::display`.
+#[derive(Debug, Clone, Copy)]
+pub struct DurationDisplay<'a> {
+    duration: &'a Duration,
+    style: DurationStyle,
+}
+
+impl<'a> DurationDisplay<'a> {
+    pub fn new(duration: &'a Duration, style: DurationStyle) -> Self {
+        Self { duration, style }
+    }
+
+    pub fn style(&self) -> DurationStyle {
+        self.style
+    }
+}
+
+impl fmt::Display for DurationDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.style {
+            DurationStyle::Readable => self.duration.write_readable(f),
+            DurationStyle::Hms => self.duration.write_hms(f),
+            DurationStyle::Precise => self.duration.write_precise(f),
+            DurationStyle::Iso8601 => self.duration.write_iso8601(f),
+        }
+    }
+}
@@ -1,13 +1,17 @@
 use serde::{Serialize, Deserialize};
-use crate::core::Result;
+use crate::core::{Result, SharedError};
 use crate::utils::{UtilsError};
 
+pub mod display;
 pub mod error;
+pub mod stopwatch;
 pub mod traits;
 #[cfg(test)]
 mod tests;
 
+pub use display::{DurationDisplay, DurationStyle};
 pub use error::DurationError;
+pub use stopwatch::{Stopwatch, ScopedTimer};
 pub use traits::{DurationValidatable, DurationFromInput};
 
 /// Duration represents a time span with nanosecond precision
@@ -16,6 +20,33 @@ pub struct Duration {
     total_nanos: u64,
 }
 
+/// Positional breakdown of a Duration, from whole days down to nanoseconds
+///
+/// Each field holds only its own unit (e.g. `hours` is 0-23), so together
+/// they add back up to the original duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DurationParts {
+    pub days: u64,
+    pub hours: u64,
+    pub minutes: u64,
+    pub seconds: u64,
+    pub millis: u64,
+    pub micros: u64,
+    pub nanos: u64,
+}
+
+/// Rounding applied to the remainder of a Duration division
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Round down (same as truncation, since durations are non-negative)
+    #[default]
+    Floor,
+    /// Round up whenever there is a remainder
+    Ceil,
+    /// Round to the nearest nanosecond, ties rounding up
+    Nearest,
+}
+
 impl Duration {
     // === Constants ===
     
@@ -26,40 +57,81 @@ impl Duration {
     const NANOS_PER_HOUR: u64 = 60 * Self::NANOS_PER_MINUTE;
     const NANOS_PER_DAY: u64 = 24 * Self::NANOS_PER_HOUR;
     
+    /// A zero-length duration
+    pub const ZERO: Duration = Duration::from_nanos(0);
+    
+    /// One second
+    pub const SECOND: Duration = Duration::from_seconds(1);
+    
+    /// One minute
+    pub const MINUTE: Duration = Duration::from_minutes(1);
+    
+    /// One hour
+    pub const HOUR: Duration = Duration::from_hours(1);
+    
+    /// One day
+    pub const DAY: Duration = Duration::from_days(1);
+    
     // === Constructors ===
     
     /// Create a Duration from nanoseconds
-    pub fn from_nanos(nanos: u64) -> Self {
+    pub const fn from_nanos(nanos: u64) -> Self {
         Self { total_nanos: nanos }
     }
     
     /// Create a Duration from microseconds
-    pub fn from_micros(micros: u64) -> Self {
+    pub const fn from_micros(micros: u64) -> Self {
         Self { total_nanos: micros * Self::NANOS_PER_MICRO }
     }
     
     /// Create a Duration from milliseconds
-    pub fn from_millis(millis: u64) -> Self {
+    pub const fn from_millis(millis: u64) -> Self {
         Self { total_nanos: millis * Self::NANOS_PER_MILLI }
     }
     
     /// Create a Duration from seconds
-    pub fn from_seconds(seconds: u64) -> Self {
+    pub const fn from_seconds(seconds: u64) -> Self {
         Self { total_nanos: seconds * Self::NANOS_PER_SECOND }
     }
     
+    /// Create a Duration from seconds in const context (alias of `from_seconds`)
+    ///
+    /// `const TIMEOUT: Duration = Duration::from_secs_const(30);`
+    pub const fn from_secs_const(seconds: u64) -> Self {
+        Self::from_seconds(seconds)
+    }
+    
+    /// Create a Duration from floating-point seconds, rounding to the nearest nanosecond
+    ///
+    /// Fails on negative, NaN, infinite, or out-of-range values.
+    pub fn from_secs_f64(secs: f64) -> Result<Self> {
+        if !secs.is_finite() || secs < 0.0 {
+            return Err(UtilsError::Duration(
+                DurationError::invalid_duration(format!("Seconds must be finite and non-negative, got {}", secs))
+            ).into());
+        }
+        
+        let nanos = (secs * Self::NANOS_PER_SECOND as f64).round();
+        match nanos >= u64::MAX as f64 {
+            true => Err(UtilsError::Duration(
+                DurationError::overflow(format!("{} seconds is too large to represent in nanoseconds", secs))
+            ).into()),
+            false => Ok(Self { total_nanos: nanos as u64 }),
+        }
+    }
+    
     /// Create a Duration from minutes
-    pub fn from_minutes(minutes: u64) -> Self {
+    pub const fn from_minutes(minutes: u64) -> Self {
         Self { total_nanos: minutes * Self::NANOS_PER_MINUTE }
     }
     
     /// Create a Duration from hours
-    pub fn from_hours(hours: u64) -> Self {
+    pub const fn from_hours(hours: u64) -> Self {
         Self { total_nanos: hours * Self::NANOS_PER_HOUR }
     }
     
     /// Create a Duration from days
-    pub fn from_days(days: u64) -> Self {
+    pub const fn from_days(days: u64) -> Self {
         Self { total_nanos: days * Self::NANOS_PER_DAY }
     }
     
@@ -82,7 +154,7 @@ impl Duration {
     }
     
     /// Create a zero duration
-    pub fn zero() -> Self {
+    pub const fn zero() -> Self {
         Self { total_nanos: 0 }
     }
     
@@ -96,6 +168,29 @@ impl Duration {
     
     // === Component extraction ===
     
+    /// Split into days, hours, minutes, seconds, millis, micros and nanos in one pass
+    pub fn to_parts(&self) -> DurationParts {
+        let days = self.total_nanos / Self::NANOS_PER_DAY;
+        let rest = self.total_nanos % Self::NANOS_PER_DAY;
+        let hours = rest / Self::NANOS_PER_HOUR;
+        let rest = rest % Self::NANOS_PER_HOUR;
+        let minutes = rest / Self::NANOS_PER_MINUTE;
+        let rest = rest % Self::NANOS_PER_MINUTE;
+        let seconds = rest / Self::NANOS_PER_SECOND;
+        let rest = rest % Self::NANOS_PER_SECOND;
+        let millis = rest / Self::NANOS_PER_MILLI;
+        let rest = rest % Self::NANOS_PER_MILLI;
+        DurationParts {
+            days,
+            hours,
+            minutes,
+            seconds,
+            millis,
+            micros: rest / Self::NANOS_PER_MICRO,
+            nanos: rest % Self::NANOS_PER_MICRO,
+        }
+    }
+    
     /// Get the hours component (0-23 for time-of-day, or total hours)
     pub fn hours(&self) -> u64 {
         (self.total_nanos / Self::NANOS_PER_HOUR) % 24
@@ -148,6 +243,12 @@ impl Duration {
         self.total_nanos / Self::NANOS_PER_SECOND
     }
     
+    /// Get total duration as floating-point seconds
+    pub fn as_secs_f64(&self) -> f64 {
+        let subsec_nanos = self.total_nanos % Self::NANOS_PER_SECOND;
+        self.total_seconds() as f64 + subsec_nanos as f64 / Self::NANOS_PER_SECOND as f64
+    }
+    
     /// Get total duration as minutes
     pub fn total_minutes(&self) -> u64 {
         self.total_nanos / Self::NANOS_PER_MINUTE
@@ -165,28 +266,79 @@ impl Duration {
     
     // === Arithmetic operations ===
     
-    /// Add two durations
-    pub fn add(&self, other: &Duration) -> Result<Duration> {
+    /// Add two durations, returning `None` on overflow
+    pub fn checked_add(&self, other: &Duration) -> Option<Duration> {
         self.total_nanos.checked_add(other.total_nanos)
             .map(|total| Duration { total_nanos: total })
+    }
+    
+    /// Subtract a duration from this one, returning `None` if the result would be negative
+    pub fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        self.total_nanos.checked_sub(other.total_nanos)
+            .map(|total| Duration { total_nanos: total })
+    }
+    
+    /// Multiply duration by a factor, returning `None` on overflow
+    pub fn checked_mul(&self, factor: u64) -> Option<Duration> {
+        self.total_nanos.checked_mul(factor)
+            .map(|total| Duration { total_nanos: total })
+    }
+    
+    /// Add two durations, clamping at the maximum representable duration
+    pub fn saturating_add(&self, other: &Duration) -> Duration {
+        Duration { total_nanos: self.total_nanos.saturating_add(other.total_nanos) }
+    }
+    
+    /// Subtract a duration from this one, clamping at zero
+    pub fn saturating_sub(&self, other: &Duration) -> Duration {
+        Duration { total_nanos: self.total_nanos.saturating_sub(other.total_nanos) }
+    }
+    
+    /// Get the absolute difference between two durations
+    pub fn abs_diff(&self, other: &Duration) -> Duration {
+        Duration { total_nanos: self.total_nanos.abs_diff(other.total_nanos) }
+    }
+    
+    /// Total a sequence of durations, failing on overflow
+    ///
+    /// Accepts owned or borrowed durations. Use `.sum::<Duration>()` instead
+    /// when clamping at the maximum is acceptable.
+    pub fn try_sum<I>(durations: I) -> Result<Duration>
+    where
+        I: IntoIterator,
+        I::Item: std::borrow::Borrow<Duration>,
+    {
+        use std::borrow::Borrow;
+        durations.into_iter().try_fold(Duration::ZERO, |total, duration| {
+            total.checked_add(duration.borrow())
+                .ok_or_else(|| UtilsError::Duration(
+                    DurationError::overflow("Sum of durations would overflow")
+                ).into())
+        })
+    }
+    
+    /// Add two durations
+    #[deprecated(since = "0.2.0", note = "use `checked_add` or `saturating_add` instead")]
+    pub fn add(&self, other: &Duration) -> Result<Duration> {
+        self.checked_add(other)
             .ok_or_else(|| UtilsError::Duration(
                 DurationError::overflow("Duration addition would overflow")
             ).into())
     }
     
     /// Subtract a duration from this one
+    #[deprecated(since = "0.2.0", note = "use `checked_sub` or `saturating_sub` instead")]
     pub fn subtract(&self, other: &Duration) -> Result<Duration> {
-        self.total_nanos.checked_sub(other.total_nanos)
-            .map(|total| Duration { total_nanos: total })
+        self.checked_sub(other)
             .ok_or_else(|| UtilsError::Duration(
                 DurationError::underflow("Duration subtraction would underflow")
             ).into())
     }
     
     /// Multiply duration by a factor
+    #[deprecated(since = "0.2.0", note = "use `checked_mul` instead")]
     pub fn multiply(&self, factor: u64) -> Result<Duration> {
-        self.total_nanos.checked_mul(factor)
-            .map(|total| Duration { total_nanos: total })
+        self.checked_mul(factor)
             .ok_or_else(|| UtilsError::Duration(
                 DurationError::overflow("Duration multiplication would overflow")
             ).into())
@@ -202,6 +354,151 @@ impl Duration {
         }
     }
     
+    /// Divide by an integer with explicit rounding of the remainder
+    pub fn divide_round(&self, divisor: u64, mode: RoundingMode) -> Result<Duration> {
+        let (quotient, remainder) = self.divmod(divisor)?;
+        let round_up = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => !remainder.is_zero(),
+            RoundingMode::Nearest => remainder.total_nanos >= divisor - remainder.total_nanos,
+        };
+        match round_up {
+            true => Ok(Duration { total_nanos: quotient.total_nanos + 1 }),
+            false => Ok(quotient),
+        }
+    }
+    
+    /// Divide by an integer, returning the quotient and the leftover remainder
+    ///
+    /// `quotient * divisor + remainder` always equals the original duration.
+    pub fn divmod(&self, divisor: u64) -> Result<(Duration, Duration)> {
+        match divisor {
+            0 => Err(UtilsError::Duration(
+                DurationError::arithmetic_error("Cannot divide duration by zero")
+            ).into()),
+            d => Ok((
+                Duration { total_nanos: self.total_nanos / d },
+                Duration { total_nanos: self.total_nanos % d },
+            )),
+        }
+    }
+    
+    /// Scale by a floating-point factor, rounding to the nearest nanosecond
+    ///
+    /// Fails on negative, NaN or infinite factors and when the result overflows.
+    pub fn mul_f64(&self, factor: f64) -> Result<Duration> {
+        if !factor.is_finite() || factor < 0.0 {
+            return Err(UtilsError::Duration(
+                DurationError::arithmetic_error(format!("Factor must be finite and non-negative, got {}", factor))
+            ).into());
+        }
+        Self::from_scaled_nanos(self.total_nanos as f64 * factor)
+    }
+    
+    /// Divide by a floating-point divisor, rounding to the nearest nanosecond
+    ///
+    /// Fails on zero, negative, NaN or infinite divisors and when the result overflows.
+    pub fn div_f64(&self, divisor: f64) -> Result<Duration> {
+        if !divisor.is_finite() || divisor <= 0.0 {
+            return Err(UtilsError::Duration(
+                DurationError::arithmetic_error(format!("Divisor must be finite and positive, got {}", divisor))
+            ).into());
+        }
+        Self::from_scaled_nanos(self.total_nanos as f64 / divisor)
+    }
+    
+    fn from_scaled_nanos(nanos: f64) -> Result<Duration> {
+        let nanos = nanos.round();
+        match nanos >= u64::MAX as f64 {
+            true => Err(UtilsError::Duration(
+                DurationError::overflow(format!("Scaled duration of {} nanoseconds is too large to represent", nanos))
+            ).into()),
+            false => Ok(Duration { total_nanos: nanos as u64 }),
+        }
+    }
+    
+    // === Rates ===
+    
+    /// Throughput of `count` items over this duration, in items per second
+    ///
+    /// A zero duration yields `f64::INFINITY` (or `0.0` when `count` is also 0)
+    /// instead of an error, so benchmark code can report it as-is.
+    pub fn rate_per_second(&self, count: u64) -> f64 {
+        match (count, self.is_zero()) {
+            (0, _) => 0.0,
+            (_, true) => f64::INFINITY,
+            _ => count as f64 / self.as_secs_f64(),
+        }
+    }
+    
+    /// Throughput in items per minute (see `rate_per_second` for zero durations)
+    pub fn rate_per_minute(&self, count: u64) -> f64 {
+        self.rate_per_second(count) * 60.0
+    }
+    
+    /// Throughput in items per hour (see `rate_per_second` for zero durations)
+    pub fn rate_per_hour(&self, count: u64) -> f64 {
+        self.rate_per_second(count) * 3_600.0
+    }
+    
+    /// Time needed to process `count` items at `rate_per_sec` items per second
+    ///
+    /// Fails when the rate is zero, negative, NaN or infinite, or the result overflows.
+    pub fn time_for(count: u64, rate_per_sec: f64) -> Result<Duration> {
+        if !rate_per_sec.is_finite() || rate_per_sec <= 0.0 {
+            return Err(UtilsError::Duration(
+                DurationError::arithmetic_error(format!("Rate must be finite and positive, got {}", rate_per_sec))
+            ).into());
+        }
+        Self::from_secs_f64(count as f64 / rate_per_sec)
+    }
+    
+    // === Statistics ===
+    
+    /// Arithmetic mean of a batch of durations, or None when empty
+    ///
+    /// Sums in `u128` so large batches cannot overflow; the result is truncated
+    /// to whole nanoseconds.
+    pub fn mean(durations: &[Duration]) -> Option<Duration> {
+        let count = durations.len() as u128;
+        let total: u128 = durations.iter().map(|d| d.total_nanos as u128).sum();
+        match count {
+            0 => None,
+            // The mean never exceeds the largest input, so it always fits in u64
+            c => Some(Duration { total_nanos: (total / c) as u64 }),
+        }
+    }
+    
+    /// Median of a batch of durations, or None when empty
+    ///
+    /// Same as `percentile(durations, 50.0)`: for an even count it is the
+    /// midpoint of the two middle values, rounded to the nearest nanosecond.
+    pub fn median(durations: &[Duration]) -> Option<Duration> {
+        Self::percentile(durations, 50.0)
+    }
+    
+    /// The `p`-th percentile (0 to 100) of a batch of durations
+    ///
+    /// Uses linear interpolation between closest ranks: the rank is
+    /// `p / 100 * (n - 1)` over the sorted values, and a fractional rank
+    /// interpolates between its neighbours, rounding half away from zero to
+    /// the nearest nanosecond. Returns None for an empty slice or when `p` is
+    /// NaN or outside 0..=100. The input does not need to be sorted.
+    pub fn percentile(durations: &[Duration], p: f64) -> Option<Duration> {
+        if durations.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let mut sorted: Vec<u64> = durations.iter().map(|d| d.total_nanos).collect();
+        sorted.sort_unstable();
+        
+        let rank = p / 100.0 * (sorted.len() - 1) as f64;
+        let lower_index = rank.floor() as usize;
+        let lower = sorted[lower_index];
+        let upper = sorted[(lower_index + 1).min(sorted.len() - 1)];
+        let offset = ((upper - lower) as f64 * rank.fract()).round() as u64;
+        Some(Duration { total_nanos: lower + offset })
+    }
+    
     // === Comparison methods ===
     
     /// Check if this duration is zero
@@ -209,6 +506,24 @@ impl Duration {
         self.total_nanos == 0
     }
     
+    /// Check if this duration is longer than zero
+    pub fn is_positive(&self) -> bool {
+        self.total_nanos > 0
+    }
+    
+    /// Always false; `Duration` is unsigned. Kept so sign-aware code reads the same for any duration type
+    pub fn is_negative(&self) -> bool {
+        false
+    }
+    
+    /// Get the sign of this duration: 0 for zero, 1 otherwise (never -1, since `Duration` is unsigned)
+    pub fn signum(&self) -> i8 {
+        match self.total_nanos {
+            0 => 0,
+            _ => 1,
+        }
+    }
+    
     /// Check if this duration is longer than another
     pub fn is_longer_than(&self, other: &Duration) -> bool {
         self.total_nanos > other.total_nanos
@@ -221,8 +536,81 @@ impl Duration {
     
     // === Formatting methods ===
     
+    /// Borrow this duration as a `Display` adapter using the given style
+    ///
+    /// Writes straight into the formatter, so it can be used with `format!`,
+    /// `write!` and logging macros without building an intermediate `String`.
+    pub fn display(&self, style: DurationStyle) -> DurationDisplay<'_> {
+        DurationDisplay::new(self, style)
+    }
+    
     /// Format duration in a human-readable way
     pub fn to_readable(&self) -> String {
+        self.display(DurationStyle::Readable).to_string()
+    }
+    
+    /// Format duration in a human-readable way, keeping at most `max_units` units
+    ///
+    /// Counting starts at the largest nonzero unit and lower units are
+    /// truncated: `1d 2h 3m 4s` with `max_units = 2` becomes `1d 2h`. Units
+    /// below a second (`ms`, `μs`, `ns`) are used when nothing larger is set,
+    /// and a zero duration is shown as `0s`. A `max_units` of 0 is treated as 1.
+    pub fn to_readable_rounded(&self, max_units: usize) -> String {
+        let parts = self.to_parts();
+        let units = [
+            (parts.days, "d"),
+            (parts.hours, "h"),
+            (parts.minutes, "m"),
+            (parts.seconds, "s"),
+            (parts.millis, "ms"),
+            (parts.micros, "μs"),
+            (parts.nanos, "ns"),
+        ];
+
+        match units.iter().position(|(value, _)| *value > 0) {
+            None => "0s".to_string(),
+            Some(first) => units[first..]
+                .iter()
+                .take(max_units.max(1))
+                .filter(|(value, _)| *value > 0)
+                .map(|(value, unit)| format!("{}{}", value, unit))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+    
+    /// Format duration as HH:MM:SS
+    pub fn to_hms(&self) -> String {
+        self.display(DurationStyle::Hms).to_string()
+    }
+    
+    /// Format as a countdown timer: MM:SS under an hour, HH:MM:SS otherwise
+    ///
+    /// Fields are zero-padded, hours grow past two digits (`100:00:00`) and
+    /// sub-second parts are dropped rather than rounded.
+    pub fn to_countdown(&self) -> String {
+        match self.total_hours() {
+            0 => format!("{:02}:{:02}", self.minutes(), self.seconds()),
+            hours => format!("{:02}:{:02}:{:02}", hours, self.minutes(), self.seconds()),
+        }
+    }
+    
+    /// Format as a countdown timer that always shows hours (HH:MM:SS, same as `to_hms`)
+    pub fn to_countdown_with_hours(&self) -> String {
+        self.to_hms()
+    }
+    
+    /// Format duration with full precision
+    pub fn to_precise(&self) -> String {
+        self.display(DurationStyle::Precise).to_string()
+    }
+    
+    /// Format duration as ISO 8601 duration string (P[n]Y[n]M[n]DT[n]H[n]M[n]S)
+    pub fn to_iso8601(&self) -> String {
+        self.display(DurationStyle::Iso8601).to_string()
+    }
+    
+    fn write_readable(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let total_hours = self.total_hours();
         let m = self.minutes();
         let s = self.seconds();
@@ -233,94 +621,77 @@ impl Duration {
                 let days = self.total_days();
                 let remaining_hours = h % 24;
                 match (remaining_hours, m, s) {
-                    (0, 0, 0) => format!("{}d", days),
-                    _ => format!("{}d {}h {}m {}s", days, remaining_hours, m, s),
+                    (0, 0, 0) => write!(f, "{}d", days),
+                    _ => write!(f, "{}d {}h {}m {}s", days, remaining_hours, m, s),
                 }
             }
-            (h, _, _, _) if h > 0 => format!("{}h {}m {}s", h, m, s),
+            (h, _, _, _) if h > 0 => write!(f, "{}h {}m {}s", h, m, s),
             (0, m, _, _) if m > 0 => match (s, ms) {
-                (0, 0) => format!("{}m", m),
-                _ => format!("{}m {}s", m, s),
+                (0, 0) => write!(f, "{}m", m),
+                _ => write!(f, "{}m {}s", m, s),
             },
             (0, 0, s, ms) if s > 0 => match ms {
-                0 => format!("{}s", s),
-                _ => format!("{}.{:03}s", s, ms),
+                0 => write!(f, "{}s", s),
+                _ => write!(f, "{}.{:03}s", s, ms),
             },
-            (0, 0, 0, ms) if ms > 0 => format!("{}ms", ms),
+            (0, 0, 0, ms) if ms > 0 => write!(f, "{}ms", ms),
             _ => {
                 let total_micros = self.total_micros();
                 match total_micros {
-                    0 => format!("{}ns", self.total_nanos),
-                    _ => format!("{}μs", total_micros),
+                    0 => write!(f, "{}ns", self.total_nanos),
+                    _ => write!(f, "{}μs", total_micros),
                 }
             }
         }
     }
     
-    /// Format duration as HH:MM:SS
-    pub fn to_hms(&self) -> String {
-        let total_hours = self.total_hours();
-        let m = self.minutes();
-        let s = self.seconds();
-        format!("{:02}:{:02}:{:02}", total_hours, m, s)
+    fn write_hms(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.total_hours(), self.minutes(), self.seconds())
     }
     
-    /// Format duration with full precision
-    pub fn to_precise(&self) -> String {
-        let total_hours = self.total_hours();
-        let m = self.minutes();
-        let s = self.seconds();
+    fn write_precise(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let remaining_nanos = self.total_nanos % Self::NANOS_PER_SECOND;
-        format!("{:02}:{:02}:{:02}.{:09}", total_hours, m, s, remaining_nanos)
+        write!(f, "{:02}:{:02}:{:02}.{:09}", self.total_hours(), self.minutes(), self.seconds(), remaining_nanos)
     }
     
-    /// Format duration as ISO 8601 duration string (P[n]Y[n]M[n]DT[n]H[n]M[n]S)
-    pub fn to_iso8601(&self) -> String {
+    fn write_iso8601(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let days = self.total_days();
         let hours = (self.total_nanos % Self::NANOS_PER_DAY) / Self::NANOS_PER_HOUR;
         let minutes = (self.total_nanos % Self::NANOS_PER_HOUR) / Self::NANOS_PER_MINUTE;
         let seconds = (self.total_nanos % Self::NANOS_PER_MINUTE) / Self::NANOS_PER_SECOND;
         let subsec_nanos = self.total_nanos % Self::NANOS_PER_SECOND;
         
-        let mut components = Vec::new();
-        
         // Add days component if present
-        if days > 0 {
-            components.push(format!("P{}DT", days));
-        } else {
-            components.push("PT".to_string());
+        match days {
+            0 => f.write_str("PT")?,
+            d => write!(f, "P{}DT", d)?,
         }
         
-        // Add time components using functional approach
-        [
-            (hours, "H"),
-            (minutes, "M"),
-        ]
-        .iter()
-        .filter(|(value, _)| *value > 0)
-        .for_each(|(value, unit)| components.push(format!("{}{}", value, unit)));
+        // Add time components
+        let mut wrote_component = false;
+        for (value, unit) in [(hours, "H"), (minutes, "M")] {
+            if value > 0 {
+                write!(f, "{}{}", value, unit)?;
+                wrote_component = true;
+            }
+        }
         
         // Handle seconds with potential fractional part
         match (seconds, subsec_nanos) {
-            (0, 0) => {
-                // Only add seconds if no other time components were added
-                if components.len() == 1 {
-                    components.push("0S".to_string());
-                }
-            }
-            (s, 0) => components.push(format!("{}S", s)),
+            // Only add seconds if no other time components were added
+            (0, 0) if !wrote_component => f.write_str("0S"),
+            (0, 0) => Ok(()),
+            (s, 0) => write!(f, "{}S", s),
             (s, ns) => {
                 let fractional = ns as f64 / Self::NANOS_PER_SECOND as f64;
-                components.push(format!("{:.9}S", s as f64 + fractional));
+                write!(f, "{:.9}S", s as f64 + fractional)
             }
         }
-        
-        components.join("")
     }
     
     // === Parsing methods ===
     
-    /// Parse duration string like "1h30m45s" or "2:30:15"
+    /// Parse duration string like "1h30m45s", "1.5h", "500ms" or "2:30:15"
     pub fn parse_duration_string(input: &str) -> Result<Duration> {
         let input = input.trim();
         
@@ -333,6 +704,65 @@ impl Duration {
         Self::parse_component_format(input)
     }
     
+    /// Parse a stopwatch-style clock string: "SS", "MM:SS" or "HH:MM:SS"
+    ///
+    /// The leading field is unbounded, so "90:00" is 90 minutes and "75" is 75
+    /// seconds. Every later field must be exactly two digits below 60. The last
+    /// field may carry up to 9 fractional digits ("01:02:03.250"). Surrounding
+    /// whitespace is ignored; signs, units and empty fields are rejected.
+    pub fn from_hms(input: &str) -> Result<Duration> {
+        fn parse_error(msg: impl Into<String>) -> SharedError {
+            UtilsError::Duration(DurationError::cannot_parse_duration(msg)).into()
+        }
+
+        let input = input.trim();
+        let (clock, fraction) = match input.split_once('.') {
+            Some((clock, fraction)) => (clock, fraction),
+            None => (input, ""),
+        };
+        let fields: Vec<&str> = clock.split(':').collect();
+        if fields.len() > 3 {
+            return Err(parse_error(format!("Expected SS, MM:SS or HH:MM:SS, got '{}'", input)));
+        }
+
+        let is_digits = |text: &str| !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit());
+        let well_formed = fields.iter().enumerate().all(|(index, field)| match index {
+            0 => is_digits(field),
+            _ => field.len() == 2 && is_digits(field),
+        });
+        let fraction_ok = match input.contains('.') {
+            true => fraction.len() <= 9 && is_digits(fraction),
+            false => true,
+        };
+        if !well_formed || !fraction_ok {
+            return Err(parse_error(format!("Expected SS, MM:SS or HH:MM:SS with optional .fffffffff, got '{}'", input)));
+        }
+
+        const UNITS: [(u64, &str); 3] = [
+            (Duration::NANOS_PER_SECOND, "Seconds"),
+            (Duration::NANOS_PER_MINUTE, "Minutes"),
+            (Duration::NANOS_PER_HOUR, "Hours"),
+        ];
+        let last = fields.len() - 1;
+        let mut total_nanos = format!("{:0<9}", fraction).parse::<u64>().unwrap_or(0);
+        for (position, field) in fields.iter().rev().enumerate() {
+            let (multiplier, name) = UNITS[position];
+            let value = field.parse::<u64>().map_err(|_| UtilsError::Duration(
+                DurationError::overflow(format!("{} value '{}' is too large", name, field))
+            ))?;
+            if position != last && value >= 60 {
+                return Err(UtilsError::Duration(
+                    DurationError::invalid_time_component(format!("{} must be less than 60, got {}", name, value))
+                ).into());
+            }
+            total_nanos = value.checked_mul(multiplier)
+                .and_then(|nanos| total_nanos.checked_add(nanos))
+                .ok_or_else(|| UtilsError::Duration(DurationError::overflow("Duration would overflow")))?;
+        }
+
+        Ok(Duration { total_nanos })
+    }
+    
     /// Parse HH:MM:SS format
     fn parse_hms_format(input: &str) -> Result<Duration> {
         let mut parts = input.split(':');
@@ -369,66 +799,94 @@ impl Duration {
         }
     }
     
-    /// Parse component format like "1h30m45s"
+    /// Parse component format like "1h30m45s", "1.5h" or "2m 500ms"
+    ///
+    /// Units are `d`, `h`, `m`, `s`, `ms`, `us`/`µs` and `ns`; the whole run of
+    /// letters after a number is read as the unit, so `ms` is never split into
+    /// minutes plus a stray `s`. Fractions are converted exactly and rounded
+    /// to the nearest nanosecond.
     fn parse_component_format(input: &str) -> Result<Duration> {
+        use std::iter::Peekable;
         use std::str::Chars;
-        
-        fn parse_number_and_unit(chars: &mut std::iter::Peekable<Chars>) -> Option<(u64, char)> {
-            // Skip whitespace
-            while chars.peek() == Some(&' ') {
-                chars.next();
-            }
-            
-            // Collect digits
-            let mut number_str = String::new();
+
+        // Collect a run of characters matching `pred`
+        fn take_while(chars: &mut Peekable<Chars>, pred: impl Fn(char) -> bool) -> String {
+            let mut out = String::new();
             while let Some(&ch) = chars.peek() {
-                if ch.is_ascii_digit() {
-                    number_str.push(chars.next().unwrap());
-                } else {
-                    break;
+                match pred(ch) {
+                    true => out.push(chars.next().unwrap()),
+                    false => break,
                 }
             }
-            
-            if number_str.is_empty() {
-                return None;
-            }
-            
-            let value = number_str.parse::<u64>().ok()?;
-            let unit = chars.next()?;
-            
-            Some((value, unit))
+            out
         }
-        
+
+        fn parse_error(msg: impl Into<String>) -> SharedError {
+            UtilsError::Duration(DurationError::cannot_parse_duration(msg)).into()
+        }
+
+        fn overflow(msg: &str) -> SharedError {
+            UtilsError::Duration(DurationError::overflow(msg)).into()
+        }
+
         let mut chars = input.chars().peekable();
         let mut total_nanos = 0u64;
-        
-        while chars.peek().is_some() {
-            let (value, unit) = parse_number_and_unit(&mut chars)
-                .ok_or_else(|| UtilsError::Duration(
-                    DurationError::cannot_parse_duration("Invalid format: expected number followed by unit")
-                ))?;
-            
-            let multiplier = match unit.to_ascii_lowercase() {
-                'd' => Self::NANOS_PER_DAY,
-                'h' => Self::NANOS_PER_HOUR,
-                'm' => Self::NANOS_PER_MINUTE,
-                's' => Self::NANOS_PER_SECOND,
-                _ => return Err(UtilsError::Duration(
-                    DurationError::cannot_parse_duration(format!("Unknown time unit: {}", unit))
-                ).into()),
+
+        loop {
+            take_while(&mut chars, char::is_whitespace);
+            if chars.peek().is_none() {
+                break;
+            }
+
+            let whole = take_while(&mut chars, |ch| ch.is_ascii_digit());
+            let fraction = match chars.peek() {
+                Some('.') => {
+                    chars.next();
+                    take_while(&mut chars, |ch| ch.is_ascii_digit())
+                }
+                _ => String::new(),
             };
-            
-            let component_nanos = value.checked_mul(multiplier)
-                .ok_or_else(|| UtilsError::Duration(
-                    DurationError::overflow("Duration component would overflow")
-                ))?;
-            
+            if whole.is_empty() && fraction.is_empty() {
+                return Err(parse_error("Invalid format: expected number followed by unit"));
+            }
+
+            let unit = take_while(&mut chars, char::is_alphabetic);
+            let multiplier = match unit.to_lowercase().as_str() {
+                "d" => Self::NANOS_PER_DAY,
+                "h" => Self::NANOS_PER_HOUR,
+                "m" => Self::NANOS_PER_MINUTE,
+                "s" => Self::NANOS_PER_SECOND,
+                "ms" => Self::NANOS_PER_MILLI,
+                "us" | "µs" | "μs" => Self::NANOS_PER_MICRO,
+                "ns" => 1,
+                "" => return Err(parse_error("Invalid format: expected number followed by unit")),
+                other => return Err(parse_error(format!("Unknown time unit: {}", other))),
+            };
+
+            let whole_value = match whole.is_empty() {
+                true => 0,
+                false => whole.parse::<u64>().map_err(|_| overflow("Duration component would overflow"))?,
+            };
+            let whole_nanos = whole_value.checked_mul(multiplier)
+                .ok_or_else(|| overflow("Duration component would overflow"))?;
+
+            // Digits past the 18th are worth well under a nanosecond, even for days
+            let fraction = &fraction[..fraction.len().min(18)];
+            let fraction_nanos = match fraction.is_empty() {
+                true => 0,
+                false => {
+                    let scale = 10u128.pow(fraction.len() as u32);
+                    let numerator = fraction.parse::<u128>().unwrap_or(0) * multiplier as u128;
+                    ((numerator + scale / 2) / scale) as u64
+                }
+            };
+
+            let component_nanos = whole_nanos.checked_add(fraction_nanos)
+                .ok_or_else(|| overflow("Duration component would overflow"))?;
             total_nanos = total_nanos.checked_add(component_nanos)
-                .ok_or_else(|| UtilsError::Duration(
-                    DurationError::overflow("Total duration would overflow")
-                ))?;
+                .ok_or_else(|| overflow("Total duration would overflow"))?;
         }
-        
+
         Ok(Duration { total_nanos })
     }
     
@@ -463,6 +921,83 @@ impl Default for Duration {
 // === Display implementation ===
 impl std::fmt::Display for Duration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.to_readable())
+        self.write_readable(f)
+    }
+}
+
+// === Conversion trait implementations ===
+impl TryFrom<std::time::Duration> for Duration {
+    type Error = SharedError;
+    
+    fn try_from(value: std::time::Duration) -> Result<Self> {
+        u64::try_from(value.as_nanos())
+            .map(Duration::from_nanos)
+            .map_err(|_| UtilsError::Duration(
+                DurationError::overflow("std::time::Duration too large to convert to nanoseconds")
+            ).into())
+    }
+}
+
+impl From<Duration> for std::time::Duration {
+    fn from(value: Duration) -> Self {
+        value.to_std_duration()
+    }
+}
+
+// === Iterator trait implementations ===
+// Sums saturate at the maximum duration instead of panicking; use `Duration::try_sum` to detect overflow
+impl std::iter::Sum for Duration {
+    fn sum<I: Iterator<Item = Duration>>(iter: I) -> Self {
+        iter.fold(Duration::ZERO, |total, duration| total.saturating_add(&duration))
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Duration> for Duration {
+    fn sum<I: Iterator<Item = &'a Duration>>(iter: I) -> Self {
+        iter.fold(Duration::ZERO, |total, duration| total.saturating_add(duration))
+    }
+}
+
+// === Comparison with std::time::Duration ===
+// Compared as u128 nanoseconds, so std values beyond u64::MAX nanos are simply greater
+impl PartialEq<std::time::Duration> for Duration {
+    fn eq(&self, other: &std::time::Duration) -> bool {
+        self.total_nanos as u128 == other.as_nanos()
+    }
+}
+
+impl PartialEq<Duration> for std::time::Duration {
+    fn eq(&self, other: &Duration) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<std::time::Duration> for Duration {
+    fn partial_cmp(&self, other: &std::time::Duration) -> Option<std::cmp::Ordering> {
+        Some((self.total_nanos as u128).cmp(&other.as_nanos()))
+    }
+}
+
+impl PartialOrd<Duration> for std::time::Duration {
+    fn partial_cmp(&self, other: &Duration) -> Option<std::cmp::Ordering> {
+        other.partial_cmp(self).map(std::cmp::Ordering::reverse)
+    }
+}
+
+impl TryFrom<chrono::Duration> for Duration {
+    type Error = SharedError;
+    
+    fn try_from(value: chrono::Duration) -> Result<Self> {
+        let total_nanos = value.num_nanoseconds()
+            .ok_or_else(|| UtilsError::Duration(
+                DurationError::overflow("Chrono duration too large to convert to nanoseconds")
+            ))?;
+        
+        match total_nanos < 0 {
+            true => Err(UtilsError::Duration(
+                DurationError::underflow("Cannot create Duration from negative chrono::Duration")
+            ).into()),
+            false => Ok(Duration::from_nanos(total_nanos as u64)),
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,87 @@
+use std::time::Instant;
+use crate::utils::time::duration::Duration;
+
+/// Convert a monotonic std duration, saturating at `u64::MAX` nanoseconds (~584 years)
+fn from_std_saturating(value: std::time::Duration) -> Duration {
+    Duration::from_nanos(u64::try_from(value.as_nanos()).unwrap_or(u64::MAX))
+}
+
+/// Stopwatch measures wall time on the monotonic clock and reports it as a `Duration`
+///
+/// `elapsed` is the time since start (or the last `reset`); `lap` is the time
+/// since the previous lap and starts a new one.
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    started: Instant,
+    lap_started: Instant,
+}
+
+impl Stopwatch {
+    /// Start a new stopwatch
+    pub fn start() -> Self {
+        let now = Instant::now();
+        Self { started: now, lap_started: now }
+    }
+
+    /// Get the time since the stopwatch was started or last reset
+    pub fn elapsed(&self) -> Duration {
+        from_std_saturating(self.started.elapsed())
+    }
+
+    /// Get the time since the previous lap (or start) and begin a new lap
+    pub fn lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let lap = from_std_saturating(now.duration_since(self.lap_started));
+        self.lap_started = now;
+        lap
+    }
+
+    /// Restart the stopwatch, clearing elapsed time and laps
+    pub fn reset(&mut self) {
+        *self = Self::start();
+    }
+}
+
+impl Default for Stopwatch {
+    fn default() -> Self {
+        Self::start()
+    }
+}
+
+/// ScopedTimer logs how long a scope took when it is dropped
+///
+/// The elapsed time is emitted as a `tracing` info event with the timer's label.
+///
+/// ```ignore
+/// let _timer = ScopedTimer::new("load_sih_files");
+/// // ... work ...
+/// // logs "load_sih_files took 1s 250ms" here
+/// ```
+#[derive(Debug)]
+pub struct ScopedTimer {
+    label: String,
+    stopwatch: Stopwatch,
+}
+
+impl ScopedTimer {
+    /// Start timing a scope under the given label
+    pub fn new(label: impl Into<String>) -> Self {
+        Self { label: label.into(), stopwatch: Stopwatch::start() }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the time since the timer was created
+    pub fn elapsed(&self) -> Duration {
+        self.stopwatch.elapsed()
+    }
+}
+
+impl Drop for ScopedTimer {
+    fn drop(&mut self) {
+        let elapsed = self.stopwatch.elapsed();
+        tracing::info!(label = %self.label, elapsed = %elapsed, "{} took {}", self.label, elapsed);
+    }
+}
@@ -24,6 +24,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_duration_arithmetic() {
         let d1 = Duration::from_hours(1);
         let d2 = Duration::from_minutes(30);
@@ -103,4 +104,426 @@ mod tests {
         let chrono_duration = d.to_chrono_duration();
         assert_eq!(chrono_duration.num_seconds(), 5445);
     }
+
+    #[test]
+    fn test_duration_checked_arithmetic() {
+        let d1 = Duration::from_hours(1);
+        let d2 = Duration::from_minutes(30);
+
+        assert_eq!(d1.checked_add(&d2).unwrap().total_minutes(), 90);
+        assert_eq!(d1.checked_sub(&d2).unwrap().total_minutes(), 30);
+        assert_eq!(d1.checked_mul(3).unwrap().total_hours(), 3);
+
+        assert!(d2.checked_sub(&d1).is_none());
+        assert!(Duration::from_nanos(u64::MAX).checked_add(&d1).is_none());
+        assert!(Duration::from_nanos(u64::MAX).checked_mul(2).is_none());
+    }
+
+    #[test]
+    fn test_duration_saturating_arithmetic() {
+        let d1 = Duration::from_hours(1);
+        let d2 = Duration::from_minutes(30);
+
+        assert_eq!(d1.saturating_sub(&d2).total_minutes(), 30);
+        assert!(d2.saturating_sub(&d1).is_zero());
+        assert_eq!(Duration::from_nanos(u64::MAX).saturating_add(&d1).total_nanos(), u64::MAX);
+
+        assert_eq!(d1.abs_diff(&d2).total_minutes(), 30);
+        assert_eq!(d2.abs_diff(&d1).total_minutes(), 30);
+    }
+
+    #[test]
+    fn test_duration_std_conversions() {
+        let std_duration = std::time::Duration::new(90, 500);
+        let duration = Duration::try_from(std_duration).unwrap();
+        assert_eq!(duration.total_seconds(), 90);
+        assert_eq!(duration.total_nanos(), 90_000_000_500);
+
+        let back: std::time::Duration = duration.into();
+        assert_eq!(back, std_duration);
+
+        // Larger than u64 nanoseconds can hold
+        assert!(Duration::try_from(std::time::Duration::from_secs(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_duration_chrono_conversion() {
+        let duration = Duration::try_from(chrono::Duration::minutes(5)).unwrap();
+        assert_eq!(duration.total_minutes(), 5);
+
+        assert!(Duration::try_from(chrono::Duration::seconds(-1)).is_err());
+    }
+
+    #[test]
+    fn test_duration_display_styles() {
+        use crate::utils::time::DurationStyle;
+
+        let d = Duration::from_components(2, 30, 45, 0, 0);
+        assert_eq!(format!("{}", d.display(DurationStyle::Readable)), "2h 30m 45s");
+        assert_eq!(format!("{}", d.display(DurationStyle::Hms)), "02:30:45");
+        assert_eq!(format!("{}", d.display(DurationStyle::Precise)), "02:30:45.000000000");
+        assert_eq!(format!("{}", d.display(DurationStyle::Iso8601)), "PT2H30M45S");
+
+        // The adapter matches the String-returning methods
+        assert_eq!(d.display(DurationStyle::Readable).to_string(), d.to_readable());
+        assert_eq!(d.display(DurationStyle::Iso8601).to_string(), d.to_iso8601());
+    }
+
+    #[test]
+    fn test_duration_iso8601_edge_cases() {
+        assert_eq!(Duration::zero().to_iso8601(), "PT0S");
+        assert_eq!(Duration::from_days(1).to_iso8601(), "P1DT0S");
+        assert_eq!(Duration::from_minutes(90).to_iso8601(), "PT1H30M");
+        assert_eq!(Duration::from_millis(1500).to_iso8601(), "PT1.500000000S");
+    }
+
+    #[test]
+    fn test_duration_const_constructors() {
+        const TIMEOUT: Duration = Duration::from_secs_const(30);
+        const GRACE: Duration = Duration::from_millis(250);
+
+        assert_eq!(TIMEOUT.total_seconds(), 30);
+        assert_eq!(GRACE.total_millis(), 250);
+
+        assert!(Duration::ZERO.is_zero());
+        assert_eq!(Duration::SECOND.total_nanos(), 1_000_000_000);
+        assert_eq!(Duration::MINUTE.total_seconds(), 60);
+        assert_eq!(Duration::HOUR.total_minutes(), 60);
+        assert_eq!(Duration::DAY.total_hours(), 24);
+        assert_eq!(Duration::ZERO, Duration::zero());
+    }
+
+    #[test]
+    fn test_duration_secs_f64() {
+        let d = Duration::from_secs_f64(1.5).unwrap();
+        assert_eq!(d.total_millis(), 1500);
+        assert_eq!(d.as_secs_f64(), 1.5);
+
+        // Rounds to the nearest nanosecond instead of truncating
+        let d = Duration::from_secs_f64(0.000_000_000_6).unwrap();
+        assert_eq!(d.total_nanos(), 1);
+
+        assert_eq!(Duration::from_secs_f64(0.0).unwrap(), Duration::zero());
+        assert!(Duration::from_secs_f64(-1.0).is_err());
+        assert!(Duration::from_secs_f64(f64::NAN).is_err());
+        assert!(Duration::from_secs_f64(f64::INFINITY).is_err());
+        assert!(Duration::from_secs_f64(1e12).is_err());
+
+        assert_eq!(Duration::from_millis(250).as_secs_f64(), 0.25);
+    }
+
+    #[test]
+    fn test_to_readable_rounded() {
+        let d = Duration::from_days(1)
+            .checked_add(&Duration::from_components(2, 3, 4, 0, 0))
+            .unwrap();
+        assert_eq!(d.to_readable_rounded(4), "1d 2h 3m 4s");
+        assert_eq!(d.to_readable_rounded(2), "1d 2h");
+        assert_eq!(d.to_readable_rounded(1), "1d");
+        assert_eq!(d.to_readable_rounded(0), "1d");
+
+        // Zero-valued units still count towards the limit
+        let gap = Duration::from_days(1).checked_add(&Duration::from_minutes(5)).unwrap();
+        assert_eq!(gap.to_readable_rounded(2), "1d");
+        assert_eq!(gap.to_readable_rounded(3), "1d 5m");
+
+        // Sub-second durations
+        assert_eq!(Duration::from_millis(250).to_readable_rounded(1), "250ms");
+        assert_eq!(Duration::from_micros(1_500).to_readable_rounded(1), "1ms");
+        assert_eq!(Duration::from_micros(1_500).to_readable_rounded(2), "1ms 500μs");
+        assert_eq!(Duration::from_micros(42).to_readable_rounded(1), "42μs");
+        assert_eq!(Duration::from_nanos(7).to_readable_rounded(1), "7ns");
+        assert_eq!(Duration::from_millis(1_500).to_readable_rounded(2), "1s 500ms");
+
+        assert_eq!(Duration::zero().to_readable_rounded(3), "0s");
+    }
+
+    #[test]
+    fn test_to_parts() {
+        use crate::utils::time::DurationParts;
+
+        let d = Duration::from_nanos(
+            2 * 86_400_000_000_000 + 3 * 3_600_000_000_000 + 4 * 60_000_000_000
+                + 5 * 1_000_000_000 + 6 * 1_000_000 + 7 * 1_000 + 8,
+        );
+        assert_eq!(d.to_parts(), DurationParts {
+            days: 2, hours: 3, minutes: 4, seconds: 5, millis: 6, micros: 7, nanos: 8,
+        });
+
+        // Agrees with the individual accessors
+        let parts = d.to_parts();
+        assert_eq!(parts.days, d.total_days());
+        assert_eq!(parts.hours, d.hours());
+        assert_eq!(parts.minutes, d.minutes());
+        assert_eq!(parts.seconds, d.seconds());
+        assert_eq!(parts.millis, d.millis());
+        assert_eq!(parts.micros, d.micros());
+        assert_eq!(parts.nanos, d.nanos());
+
+        assert_eq!(Duration::zero().to_parts(), DurationParts::default());
+    }
+
+    #[test]
+    fn test_mul_and_div_f64() {
+        let timeout = Duration::from_seconds(10);
+        assert_eq!(timeout.mul_f64(0.8).unwrap(), Duration::from_seconds(8));
+        assert_eq!(timeout.mul_f64(1.5).unwrap(), Duration::from_seconds(15));
+        assert_eq!(timeout.mul_f64(0.0).unwrap(), Duration::zero());
+        assert_eq!(timeout.div_f64(4.0).unwrap(), Duration::from_millis(2_500));
+
+        // Rounds to the nearest nanosecond
+        assert_eq!(Duration::from_nanos(3).mul_f64(0.5).unwrap(), Duration::from_nanos(2));
+        assert_eq!(Duration::from_nanos(10).div_f64(3.0).unwrap(), Duration::from_nanos(3));
+
+        assert!(timeout.mul_f64(-1.0).is_err());
+        assert!(timeout.mul_f64(f64::NAN).is_err());
+        assert!(timeout.mul_f64(f64::INFINITY).is_err());
+        assert!(Duration::from_days(365).mul_f64(1e9).is_err());
+
+        assert!(timeout.div_f64(0.0).is_err());
+        assert!(timeout.div_f64(-2.0).is_err());
+        assert!(timeout.div_f64(f64::NAN).is_err());
+        assert!(Duration::from_days(365).div_f64(1e-12).is_err());
+    }
+
+    #[test]
+    fn test_divide_round_and_divmod() {
+        use crate::utils::time::RoundingMode;
+
+        let d = Duration::from_nanos(10);
+        assert_eq!(d.divide_round(3, RoundingMode::Floor).unwrap(), Duration::from_nanos(3));
+        assert_eq!(d.divide_round(3, RoundingMode::Ceil).unwrap(), Duration::from_nanos(4));
+        assert_eq!(d.divide_round(3, RoundingMode::Nearest).unwrap(), Duration::from_nanos(3));
+        assert_eq!(Duration::from_nanos(11).divide_round(3, RoundingMode::Nearest).unwrap(), Duration::from_nanos(4));
+
+        // Ties round up
+        assert_eq!(Duration::from_nanos(5).divide_round(2, RoundingMode::Nearest).unwrap(), Duration::from_nanos(3));
+
+        // Evenly divisible values are unaffected by the mode
+        let hour = Duration::from_hours(1);
+        for mode in [RoundingMode::Floor, RoundingMode::Ceil, RoundingMode::Nearest] {
+            assert_eq!(hour.divide_round(4, mode).unwrap(), Duration::from_minutes(15));
+        }
+
+        let (quotient, remainder) = Duration::from_seconds(10).divmod(3).unwrap();
+        assert_eq!(quotient, Duration::from_nanos(3_333_333_333));
+        assert_eq!(remainder, Duration::from_nanos(1));
+        assert_eq!(quotient.checked_mul(3).unwrap().checked_add(&remainder).unwrap(), Duration::from_seconds(10));
+
+        assert!(d.divmod(0).is_err());
+        assert!(d.divide_round(0, RoundingMode::Ceil).is_err());
+    }
+
+    #[test]
+    fn test_rates() {
+        let elapsed = Duration::from_seconds(4);
+        assert_eq!(elapsed.rate_per_second(1_000), 250.0);
+        assert_eq!(elapsed.rate_per_minute(1_000), 15_000.0);
+        assert_eq!(elapsed.rate_per_hour(1_000), 900_000.0);
+        assert_eq!(Duration::from_millis(500).rate_per_second(10), 20.0);
+
+        // Zero durations
+        assert_eq!(Duration::zero().rate_per_second(10), f64::INFINITY);
+        assert_eq!(Duration::zero().rate_per_second(0), 0.0);
+        assert_eq!(elapsed.rate_per_second(0), 0.0);
+
+        assert_eq!(Duration::time_for(1_000, 250.0).unwrap(), elapsed);
+        assert_eq!(Duration::time_for(3, 2.0).unwrap(), Duration::from_millis(1_500));
+        assert_eq!(Duration::time_for(0, 5.0).unwrap(), Duration::zero());
+        assert!(Duration::time_for(10, 0.0).is_err());
+        assert!(Duration::time_for(10, -1.0).is_err());
+        assert!(Duration::time_for(10, f64::NAN).is_err());
+        assert!(Duration::time_for(u64::MAX, 1e-9).is_err());
+    }
+
+    #[test]
+    fn test_parse_fractional_and_sub_second_units() {
+        assert_eq!(Duration::parse_duration_string("1.5h").unwrap(), Duration::from_minutes(90));
+        assert_eq!(Duration::parse_duration_string("2m30s").unwrap(), Duration::from_seconds(150));
+        assert_eq!(Duration::parse_duration_string("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(Duration::parse_duration_string("250us").unwrap(), Duration::from_micros(250));
+        assert_eq!(Duration::parse_duration_string("250µs").unwrap(), Duration::from_micros(250));
+        assert_eq!(Duration::parse_duration_string("30ns").unwrap().total_nanos(), 30);
+        assert_eq!(Duration::parse_duration_string(".5s").unwrap(), Duration::from_millis(500));
+
+        // `m` vs `ms` is resolved by reading the whole unit
+        assert_eq!(
+            Duration::parse_duration_string("1m500ms").unwrap(),
+            Duration::from_millis(60_500)
+        );
+
+        // Whitespace between components
+        assert_eq!(
+            Duration::parse_duration_string("1h 30m  15s").unwrap(),
+            Duration::from_components(1, 30, 15, 0, 0)
+        );
+
+        // Fractions round to the nearest nanosecond
+        assert_eq!(Duration::parse_duration_string("1.0005us").unwrap().total_nanos(), 1_001);
+        assert_eq!(Duration::parse_duration_string("0.4ns").unwrap().total_nanos(), 0);
+
+        assert!(Duration::parse_duration_string("5x").is_err());
+        assert!(Duration::parse_duration_string("5").is_err());
+        assert!(Duration::parse_duration_string("1h.").is_err());
+        assert!(Duration::parse_duration_string("5mss").is_err());
+
+        // HH:MM:SS still works
+        assert_eq!(
+            Duration::parse_duration_string("01:02:03").unwrap(),
+            Duration::from_components(1, 2, 3, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_stopwatch() {
+        use crate::utils::time::{Stopwatch, ScopedTimer};
+
+        let mut stopwatch = Stopwatch::start();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let first_lap = stopwatch.lap();
+        assert!(!first_lap.is_shorter_than(&Duration::from_millis(5)));
+
+        let second_lap = stopwatch.lap();
+        let elapsed = stopwatch.elapsed();
+        // Laps never exceed the total elapsed time
+        assert!(!first_lap.is_longer_than(&elapsed));
+        assert!(!second_lap.is_longer_than(&elapsed));
+
+        stopwatch.reset();
+        assert!(stopwatch.elapsed().is_shorter_than(&elapsed));
+
+        let timer = ScopedTimer::new("test scope");
+        assert_eq!(timer.label(), "test scope");
+        assert!(timer.elapsed().is_shorter_than(&Duration::from_hours(1)));
+        drop(timer);
+    }
+
+    #[test]
+    fn test_compare_with_std_duration() {
+        let d = Duration::from_millis(1500);
+        assert_eq!(d, std::time::Duration::from_millis(1500));
+        assert_eq!(std::time::Duration::from_millis(1500), d);
+        assert_ne!(d, std::time::Duration::from_millis(1501));
+
+        assert!(d < std::time::Duration::from_secs(2));
+        assert!(d > std::time::Duration::from_secs(1));
+        assert!(d <= std::time::Duration::new(1, 500_000_000));
+        assert!(std::time::Duration::from_secs(2) > d);
+        assert!(std::time::Duration::from_secs(1) < d);
+
+        // std durations past u64::MAX nanoseconds are always greater
+        let huge = std::time::Duration::from_secs(u64::MAX);
+        let max = Duration::from_nanos(u64::MAX);
+        assert_ne!(max, huge);
+        assert!(max < huge);
+        assert!(huge > max);
+    }
+
+    #[test]
+    fn test_sign_predicates() {
+        assert!(Duration::ZERO.is_zero());
+        assert!(!Duration::ZERO.is_positive());
+        assert!(!Duration::ZERO.is_negative());
+        assert_eq!(Duration::ZERO.signum(), 0);
+
+        let d = Duration::from_nanos(1);
+        assert!(!d.is_zero());
+        assert!(d.is_positive());
+        assert!(!d.is_negative());
+        assert_eq!(d.signum(), 1);
+        assert_eq!(Duration::DAY.signum(), 1);
+    }
+
+    #[test]
+    fn test_sum_durations() {
+        let durations = vec![Duration::from_minutes(1), Duration::from_seconds(30), Duration::from_millis(500)];
+
+        let total: Duration = durations.iter().sum();
+        assert_eq!(total, Duration::from_millis(90_500));
+        let total: Duration = durations.clone().into_iter().sum();
+        assert_eq!(total, Duration::from_millis(90_500));
+        assert_eq!(Duration::try_sum(&durations).unwrap(), Duration::from_millis(90_500));
+        assert_eq!(Duration::try_sum(durations).unwrap(), Duration::from_millis(90_500));
+
+        let empty: Vec<Duration> = Vec::new();
+        assert_eq!(empty.iter().sum::<Duration>(), Duration::ZERO);
+        assert_eq!(Duration::try_sum(&empty).unwrap(), Duration::ZERO);
+
+        // Overflow saturates with Sum and errors with try_sum
+        let huge = [Duration::from_nanos(u64::MAX), Duration::from_nanos(1)];
+        assert_eq!(huge.iter().sum::<Duration>(), Duration::from_nanos(u64::MAX));
+        assert!(Duration::try_sum(huge).is_err());
+    }
+
+    #[test]
+    fn test_duration_statistics() {
+        let ms = |values: &[u64]| values.iter().map(|&v| Duration::from_millis(v)).collect::<Vec<_>>();
+
+        assert_eq!(Duration::mean(&[]), None);
+        assert_eq!(Duration::median(&[]), None);
+        assert_eq!(Duration::percentile(&[], 50.0), None);
+
+        let batch = ms(&[40, 10, 30, 20]);
+        assert_eq!(Duration::mean(&batch), Some(Duration::from_millis(25)));
+        assert_eq!(Duration::median(&batch), Some(Duration::from_millis(25)));
+        assert_eq!(Duration::median(&ms(&[30, 10, 20])), Some(Duration::from_millis(20)));
+
+        assert_eq!(Duration::percentile(&batch, 0.0), Some(Duration::from_millis(10)));
+        assert_eq!(Duration::percentile(&batch, 100.0), Some(Duration::from_millis(40)));
+        // rank = 0.99 * 3 = 2.97, between 30ms and 40ms
+        assert_eq!(Duration::percentile(&batch, 99.0), Some(Duration::from_micros(39_700)));
+        assert_eq!(Duration::percentile(&batch, -1.0), None);
+        assert_eq!(Duration::percentile(&batch, 100.5), None);
+        assert_eq!(Duration::percentile(&batch, f64::NAN), None);
+
+        // Interpolation rounds to the nearest nanosecond
+        let nanos = [Duration::from_nanos(0), Duration::from_nanos(1)];
+        assert_eq!(Duration::median(&nanos), Some(Duration::from_nanos(1)));
+        assert_eq!(Duration::mean(&nanos), Some(Duration::from_nanos(0)));
+
+        // Summing in u128 avoids overflow for large values
+        let huge = [Duration::from_nanos(u64::MAX), Duration::from_nanos(u64::MAX - 2)];
+        assert_eq!(Duration::mean(&huge), Some(Duration::from_nanos(u64::MAX - 1)));
+    }
+
+    #[test]
+    fn test_to_countdown() {
+        assert_eq!(Duration::ZERO.to_countdown(), "00:00");
+        assert_eq!(Duration::from_seconds(65).to_countdown(), "01:05");
+        assert_eq!(Duration::from_millis(59_999).to_countdown(), "00:59");
+        assert_eq!(Duration::from_seconds(3_599).to_countdown(), "59:59");
+        assert_eq!(Duration::from_seconds(3_600).to_countdown(), "01:00:00");
+        assert_eq!(Duration::from_days(2).to_countdown(), "48:00:00");
+        assert_eq!(Duration::from_hours(123).to_countdown(), "123:00:00");
+
+        assert_eq!(Duration::from_seconds(65).to_countdown_with_hours(), "00:01:05");
+        assert_eq!(Duration::from_hours(100).to_countdown_with_hours(), "100:00:00");
+    }
+
+    #[test]
+    fn test_from_hms() {
+        // The leading field is unbounded, so two fields mean minutes and seconds
+        assert_eq!(Duration::from_hms("90:00").unwrap(), Duration::from_minutes(90));
+        assert!(Duration::parse_duration_string("90:00").is_err());
+        assert_eq!(Duration::from_hms("75").unwrap(), Duration::from_seconds(75));
+        assert_eq!(Duration::from_hms("01:02:03").unwrap(), Duration::from_components(1, 2, 3, 0, 0));
+        assert_eq!(Duration::from_hms("100:00:00").unwrap(), Duration::from_hours(100));
+        assert_eq!(Duration::from_hms(" 05:30 ").unwrap(), Duration::from_seconds(330));
+
+        // Fractional seconds on the last field
+        assert_eq!(Duration::from_hms("01:02:03.25").unwrap(), Duration::from_components(1, 2, 3, 250, 0));
+        assert_eq!(Duration::from_hms("00:01.000000001").unwrap(), Duration::from_nanos(1_000_000_001));
+        assert_eq!(Duration::from_hms("1.5").unwrap(), Duration::from_millis(1_500));
+
+        // Out-of-range minutes and seconds
+        assert!(Duration::from_hms("01:60").is_err());
+        assert!(Duration::from_hms("01:75:00").is_err());
+        assert!(Duration::from_hms("01:00:60").is_err());
+
+        // Malformed input
+        for input in ["", ":", "1:2", "01:02:03:04", "1:", ":30", "-1:00", "01:0a", "1h", "01:02.", "01:02.1234567890", "1.2.3", "01 :02"] {
+            assert!(Duration::from_hms(input).is_err(), "{:?} should be rejected", input);
+        }
+        assert!(Duration::from_hms("99999999999999999999").is_err());
+    }
 }
\ No newline at end of file
@@ -77,24 +77,12 @@ impl DurationFromInput for &String {
 
 impl DurationFromInput for std::time::Duration {
     fn parse_duration(self) -> Result<Duration> {
-        let total_nanos = self.as_secs() * 1_000_000_000 + self.subsec_nanos() as u64;
-        Ok(Duration::from_nanos(total_nanos))
+        Duration::try_from(self)
     }
 }
 
 impl DurationFromInput for chrono::Duration {
     fn parse_duration(self) -> Result<Duration> {
-        let total_nanos = self.num_nanoseconds()
-            .ok_or_else(|| UtilsError::Duration(
-                DurationError::overflow("Chrono duration too large to convert to nanoseconds")
-            ))?;
-        
-        if total_nanos < 0 {
-            return Err(UtilsError::Duration(
-                DurationError::underflow("Cannot create Duration from negative chrono::Duration")
-            ).into());
-        }
-        
-        Ok(Duration::from_nanos(total_nanos as u64))
+        Duration::try_from(self)
     }
 }
\ No newline at end of file
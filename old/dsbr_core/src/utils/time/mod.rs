@@ -3,9 +3,10 @@ pub mod month;
 pub mod year;
 pub mod duration;
 pub mod datetime;
+mod component_serde;
 
-pub use day::{Day, DayError, DayValidatable, DayFromInput, DAYS, DAYS_ORDERED};
-pub use month::{Month, MonthError, MonthValidatable, MonthFromInput, MONTHS, MONTHS_ORDERED};
-pub use year::{Year, YearError, YearValidatable, YearFromInput, YEARS, YEARS_ORDERED};
-pub use duration::{Duration, DurationError, DurationValidatable, DurationFromInput};
-pub use datetime::{DateTime, DateTimeBuilder, DateTimeError, DateTimeValidatable, DateTimeFromInput, DateTimeFormat};
+pub use day::{Day, DayError, DayValidatable, DayFromInput, DAYS_ORDERED, weekday_name_en, weekday_name_ptbr, weekday_short_en, weekday_short_ptbr};
+pub use month::{Month, MonthError, MonthValidatable, MonthFromInput, MONTHS_ORDERED};
+pub use year::{Year, Quarter, Semester, FiscalYear, YearError, YearValidatable, YearFromInput, YEARS, YEARS_ORDERED};
+pub use duration::{Duration, DurationParts, RoundingMode, DurationDisplay, DurationStyle, DurationError, Stopwatch, ScopedTimer, DurationValidatable, DurationFromInput};
+pub use datetime::{DateTime, DateTimeBuilder, DateTimeError, DateTimeValidatable, DateTimeFromInput, DateTimeFormat, DateOrder, DateField, DateTimeInterval, DateTimeParser, CalendarDiff, Iso8601Display, ZonedDateTime, Calendar, BrazilianCalendar, WeekendsOnlyCalendar};
@@ -22,6 +22,12 @@ pub enum MonthError {
 
     #[error("Not a valid month: {0}. Must be a month in portuguese between 'Janeiro' and 'Dezembro'.")]
     NotValidMonthPortuguese(String),
+
+    #[error("Not a valid month: {0}. Must be a month in spanish between 'Enero' and 'Diciembre'.")]
+    NotValidMonthSpanish(String),
+
+    #[error("Not a valid month: {0}. Must be a month in french between 'Janvier' and 'Décembre'.")]
+    NotValidMonthFrench(String),
     
     #[error("Cannot parse month: {0}")]
     CannotParseMonth(String),
@@ -38,6 +44,8 @@ impl MonthError {
     pub fn not_valid_month_abbreviation<S: Into<String>>(msg: S) -> Self { Self::NotValidMonthAbbreviation(msg.into()) }
     pub fn not_valid_month_english<S: Into<String>>(msg: S) -> Self { Self::NotValidMonthEnglish(msg.into()) }
     pub fn not_valid_month_portuguese<S: Into<String>>(msg: S) -> Self { Self::NotValidMonthPortuguese(msg.into()) }
+    pub fn not_valid_month_spanish<S: Into<String>>(msg: S) -> Self { Self::NotValidMonthSpanish(msg.into()) }
+    pub fn not_valid_month_french<S: Into<String>>(msg: S) -> Self { Self::NotValidMonthFrench(msg.into()) }
     pub fn cannot_parse_month<S: Into<String>>(msg: S) -> Self { Self::CannotParseMonth(msg.into()) }
     pub fn cannot_convert_month<S: Into<String>>(msg: S) -> Self { Self::CannotConvertMonth(msg.into()) }
 }
\ No newline at end of file
@@ -1,8 +1,10 @@
-use dashmap::{DashSet};
 use std::sync::LazyLock;
-use serde::{Serialize, Deserialize};
-use crate::core::{Result};
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Visitor;
+use crate::core::{Result, SharedError};
 use crate::utils::{UtilsError};
+use crate::utils::time::{Quarter, Semester, Year};
+use crate::utils::time::component_serde::{self, NumberedComponent};
 
 pub mod error;
 pub mod traits;
@@ -12,13 +14,16 @@ mod tests;
 pub use error::MonthError;
 pub use traits::{MonthValidatable, MonthFromInput};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Serialize, Deserialize)]
+/// Ordering follows the numeric `month` field; the other fields are derived from it
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 pub struct Month {
     pub month: u8,
     pub text: &'static str,
     pub name_ptbr: &'static str,
     pub name_short: &'static str,
     pub name_en: &'static str,
+    pub name_es: &'static str,
+    pub name_fr: &'static str,
 }
 
 impl Month {
@@ -42,6 +47,16 @@ impl Month {
         "January", "February", "March", "April", "May", "June",
         "July", "August", "September", "October", "November", "December"
     ];
+    
+    const MONTH_NAMES_ES: [&'static str; 12] = [
+        "Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio",
+        "Julio", "Agosto", "Septiembre", "Octubre", "Noviembre", "Diciembre"
+    ];
+    
+    const MONTH_NAMES_FR: [&'static str; 12] = [
+        "Janvier", "Février", "Mars", "Avril", "Mai", "Juin",
+        "Juillet", "Août", "Septembre", "Octobre", "Novembre", "Décembre"
+    ];
 
     /// Create a new Month from number (internal use)
     fn new_unchecked(month: u8) -> Self {
@@ -52,18 +67,12 @@ impl Month {
             name_ptbr: Self::MONTH_NAMES_PTBR[index],
             name_short: Self::MONTH_NAMES_SHORT[index],
             name_en: Self::MONTH_NAMES_EN[index],
+            name_es: Self::MONTH_NAMES_ES[index],
+            name_fr: Self::MONTH_NAMES_FR[index],
         }
     }
 }
 
-pub static MONTHS: LazyLock<DashSet<Month>> = LazyLock::new(|| {
-    let months = DashSet::with_capacity(12);
-    (1..=12).for_each(|i| {
-        months.insert(Month::new_unchecked(i));
-    });
-    months
-});
-
 pub static MONTHS_ORDERED: LazyLock<[Month; 12]> = LazyLock::new(|| {
     (1..=12)
         .map(Month::new_unchecked)
@@ -97,6 +106,19 @@ impl Month {
         months[prev_index]
     }
     
+    /// Move `n` months forward (or backward when negative) through the calendar
+    ///
+    /// Returns the resulting month and how many years were crossed, so
+    /// December + 1 is `(January, 1)` and January - 1 is `(December, -1)`.
+    /// Unlike `next`/`previous`, the rollover is never silent. Every `i8`
+    /// offset has an answer, so this currently always returns `Some`.
+    pub fn checked_add(&self, n: i8) -> Option<(Month, i32)> {
+        let zero_based = self.month as i32 - 1 + n as i32;
+        let years = zero_based.div_euclid(12);
+        let index = zero_based.rem_euclid(12) as usize;
+        Self::all_months().get(index).map(|month| (*month, years))
+    }
+    
     /// Check if this month comes before another chronologically
     pub fn is_before(&self, other: &Month) -> bool {
         self.month < other.month
@@ -149,6 +171,16 @@ impl Month {
         self.name_en
     }
 
+    /// Convert to Spanish name ("Enero", "Febrero", etc.)
+    pub fn to_es(&self) -> &'static str {
+        self.name_es
+    }
+
+    /// Convert to French name ("Janvier", "Février", etc.)
+    pub fn to_fr(&self) -> &'static str {
+        self.name_fr
+    }
+
     /// Convert to short/abbreviated name ("Jan", "Feb", etc.)
     pub fn to_short(&self) -> &'static str {
         self.name_short
@@ -162,6 +194,8 @@ impl Month {
     /// - Number string parsing (for strings like "1", "2")
     /// - English name parsing (case-insensitive)
     /// - Portuguese name parsing (case-insensitive)
+    /// - Spanish name parsing (case-insensitive)
+    /// - French name parsing (case-insensitive)
     /// - Abbreviation parsing (case-insensitive)
     pub fn from<T>(input: T) -> Result<Month>
     where
@@ -183,6 +217,51 @@ impl Month {
         }
     }
 
+    /// Fast path for canonical English names ("January" exactly)
+    ///
+    /// No case folding or fallback chain, so no allocation; returns `None`
+    /// for anything else. Meant for bulk parsing of known-clean columns.
+    pub fn from_en_exact(name: &str) -> Option<Month> {
+        let month = match name {
+            "January" => 1,
+            "February" => 2,
+            "March" => 3,
+            "April" => 4,
+            "May" => 5,
+            "June" => 6,
+            "July" => 7,
+            "August" => 8,
+            "September" => 9,
+            "October" => 10,
+            "November" => 11,
+            "December" => 12,
+            _ => return None,
+        };
+        Some(Self::all_months()[month - 1])
+    }
+
+    /// Fast path for canonical Portuguese names ("Março" exactly)
+    ///
+    /// Like `from_en_exact`, but for the pt-BR names.
+    pub fn from_ptbr_exact(name: &str) -> Option<Month> {
+        let month = match name {
+            "Janeiro" => 1,
+            "Fevereiro" => 2,
+            "Março" => 3,
+            "Abril" => 4,
+            "Maio" => 5,
+            "Junho" => 6,
+            "Julho" => 7,
+            "Agosto" => 8,
+            "Setembro" => 9,
+            "Outubro" => 10,
+            "Novembro" => 11,
+            "Dezembro" => 12,
+            _ => return None,
+        };
+        Some(Self::all_months()[month - 1])
+    }
+
     /// Find month by text representation ("01", "02", etc.)
     pub fn from_text(text: &str) -> Result<Month> {
         Self::MONTH_TEXTS
@@ -206,16 +285,44 @@ impl Month {
     }
 
     /// Find month by Portuguese name ("Janeiro", "Fevereiro", etc.)
+    ///
+    /// Case- and accent-insensitive: "Março", "MARÇO" and "marco" all match.
     pub fn from_portuguese_name(name: &str) -> Result<Month> {
         Self::MONTH_NAMES_PTBR
             .iter()
-            .position(|&month_name| month_name.to_lowercase() == name.to_lowercase())
+            .position(|&month_name| normalize_accents(month_name) == normalize_accents(name))
             .map(|index| Self::new_unchecked((index + 1) as u8))
             .ok_or_else(|| UtilsError::Month(
                 MonthError::not_valid_month_portuguese(name.to_string())
             ).into())
     }
 
+    /// Find month by Spanish name ("Enero", "Febrero", etc.)
+    ///
+    /// Case- and accent-insensitive, like `from_portuguese_name`.
+    pub fn from_spanish_name(name: &str) -> Result<Month> {
+        Self::MONTH_NAMES_ES
+            .iter()
+            .position(|&month_name| normalize_accents(month_name) == normalize_accents(name))
+            .map(|index| Self::new_unchecked((index + 1) as u8))
+            .ok_or_else(|| UtilsError::Month(
+                MonthError::not_valid_month_spanish(name.to_string())
+            ).into())
+    }
+
+    /// Find month by French name ("Janvier", "Février", etc.)
+    ///
+    /// Case- and accent-insensitive: "Février", "FÉVRIER" and "fevrier" all match.
+    pub fn from_french_name(name: &str) -> Result<Month> {
+        Self::MONTH_NAMES_FR
+            .iter()
+            .position(|&month_name| normalize_accents(month_name) == normalize_accents(name))
+            .map(|index| Self::new_unchecked((index + 1) as u8))
+            .ok_or_else(|| UtilsError::Month(
+                MonthError::not_valid_month_french(name.to_string())
+            ).into())
+    }
+
     /// Find month by abbreviation ("Jan", "Feb", etc.)
     pub fn from_abbreviation(abbr: &str) -> Result<Month> {
         Self::MONTH_NAMES_SHORT
@@ -250,7 +357,19 @@ impl Month {
     pub fn is_valid_portuguese_name(name: &str) -> bool {
         Self::all_months()
             .iter()
-            .any(|month| month.name_ptbr.to_lowercase() == name.to_lowercase())
+            .any(|month| normalize_accents(month.name_ptbr) == normalize_accents(name))
+    }
+
+    pub fn is_valid_spanish_name(name: &str) -> bool {
+        Self::all_months()
+            .iter()
+            .any(|month| normalize_accents(month.name_es) == normalize_accents(name))
+    }
+
+    pub fn is_valid_french_name(name: &str) -> bool {
+        Self::all_months()
+            .iter()
+            .any(|month| normalize_accents(month.name_fr) == normalize_accents(name))
     }
 
     pub fn is_valid_abbreviation(abbr: &str) -> bool {
@@ -270,4 +389,133 @@ impl Month {
     pub fn is_valid<T: MonthValidatable>(input: T) -> bool {
         input.is_valid_month()
     }
-}
\ No newline at end of file
+    
+    // === Month length ===
+    
+    /// Get the number of days in this month for a given year (forwards to `Year::days_in_month`)
+    pub fn days_in(&self, year: &Year) -> u8 {
+        year.days_in_month(self)
+    }
+    
+    /// Get the most days this month can have in any year (29 for February)
+    pub fn max_days(&self) -> u8 {
+        match self.month {
+            2 => 29,
+            _ => Year::all_years()[0].days_in_month(self),
+        }
+    }
+    
+    // === Quarter and semester ===
+    
+    /// Get the quarter this month falls in (the same in every year)
+    pub fn quarter(&self) -> Quarter {
+        Quarter::from_month(self)
+    }
+    
+    /// Get the semester this month falls in (the same in every year)
+    pub fn semester(&self) -> Semester {
+        Semester::from_month(self)
+    }
+}
+
+/// Lowercase a string and strip common Latin diacritics ("Março" -> "marco")
+pub fn normalize_accents(input: &str) -> String {
+    input
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            other => other,
+        })
+        .collect()
+}
+
+// === Display / FromStr implementations ===
+impl std::fmt::Display for Month {
+    /// "March" by default, "Mar" with the alternate flag (`{:#}`)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.alternate() {
+            true => write!(f, "{}", &self.name_en[..3]),
+            false => write!(f, "{}", self.name_en),
+        }
+    }
+}
+
+impl std::str::FromStr for Month {
+    type Err = SharedError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Month::from(s)
+    }
+}
+
+// === TryFrom implementations ===
+impl TryFrom<u8> for Month {
+    type Error = SharedError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Month::from(value)
+    }
+}
+
+impl TryFrom<&str> for Month {
+    type Error = SharedError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Month::from(value)
+    }
+}
+
+impl TryFrom<String> for Month {
+    type Error = SharedError;
+
+    fn try_from(value: String) -> Result<Self> {
+        Month::from(value)
+    }
+}
+
+// === Serde implementations ===
+impl Serialize for Month {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.month)
+    }
+}
+
+impl<'de> Deserialize<'de> for Month {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        component_serde::deserialize(deserializer)
+    }
+}
+
+impl NumberedComponent for Month {
+    const FIELD: &'static str = "month";
+
+    fn expecting(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a month between 1 and 12 as an integer, a string, or an object with a `month` field")
+    }
+
+    fn from_wire_number(value: i64) -> Result<Self> {
+        match u8::try_from(value) {
+            Ok(number) => Month::from(number),
+            Err(_) => Err(UtilsError::Month(MonthError::not_valid_month_number(value.to_string())).into()),
+        }
+    }
+
+    fn from_wire_str(value: &str) -> Result<Self> {
+        Month::from(value)
+    }
+
+    fn deserialize_number<'de, D, V>(deserializer: D, visitor: V) -> std::result::Result<V::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Visitor<'de>,
+    {
+        deserializer.deserialize_u8(visitor)
+    }
+}
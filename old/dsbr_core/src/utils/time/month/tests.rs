@@ -353,4 +353,227 @@ mod tests {
         // String parsing
         assert!(String::from("February").parse_month().is_ok());
     }
+
+    #[test]
+    fn test_from_spanish_name() {
+        assert_eq!(Month::from_spanish_name("Enero").unwrap().month, 1);
+        assert_eq!(Month::from_spanish_name("febrero").unwrap().month, 2);
+        assert_eq!(Month::from_spanish_name("SEPTIEMBRE").unwrap().month, 9);
+        assert_eq!(Month::from_spanish_name("diciembre").unwrap().to_es(), "Diciembre");
+
+        assert!(Month::from_spanish_name("January").is_err());
+    }
+
+    #[test]
+    fn test_from_french_name() {
+        assert_eq!(Month::from_french_name("Janvier").unwrap().month, 1);
+        assert_eq!(Month::from_french_name("février").unwrap().month, 2);
+        assert_eq!(Month::from_french_name("AOÛT").unwrap().month, 8);
+        assert_eq!(Month::from_french_name("décembre").unwrap().to_fr(), "Décembre");
+
+        assert!(Month::from_french_name("Enero").is_err());
+    }
+
+    #[test]
+    fn test_from_generic_other_locales() {
+        assert_eq!(Month::from("enero").unwrap().month, 1);
+        assert_eq!(Month::from("Noviembre").unwrap().month, 11);
+        assert_eq!(Month::from("juillet").unwrap().month, 7);
+        assert_eq!(Month::from("mars").unwrap().month, 3);
+
+        assert!(Month::is_valid("octubre"));
+        assert!(Month::is_valid("octobre"));
+    }
+
+    #[test]
+    fn test_portuguese_name_accent_insensitive() {
+        // Cedilla
+        for input in ["marco", "março", "MARÇO", "Marco", "MARCO"] {
+            assert_eq!(Month::from_portuguese_name(input).unwrap().month, 3, "input: {}", input);
+        }
+        assert!(Month::is_valid_portuguese_name("marco"));
+        assert_eq!(Month::from("marco").unwrap().month, 3);
+
+        // Unaccented names still match case-insensitively
+        assert_eq!(Month::from_portuguese_name("AGOSTO").unwrap().month, 8);
+        assert_eq!(Month::from_portuguese_name("agosto").unwrap().month, 8);
+    }
+
+    #[test]
+    fn test_normalize_accents() {
+        use crate::utils::time::month::normalize_accents;
+
+        assert_eq!(normalize_accents("Março"), "marco");
+        assert_eq!(normalize_accents("MARÇO"), "marco");
+        // Tilde
+        assert_eq!(normalize_accents("São João"), "sao joao");
+        assert_eq!(normalize_accents("Fevereiro"), "fevereiro");
+        assert_eq!(normalize_accents("Décembre"), "decembre");
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let march = Month::from_number(3).unwrap();
+        assert_eq!(march.to_string(), "March");
+        assert_eq!(format!("{:#}", march), "Mar");
+        assert_eq!(format!("{}", Month::from_number(9).unwrap()), "September");
+
+        let parsed: Month = "march".parse().unwrap();
+        assert_eq!(parsed, march);
+        let parsed: Month = "Março".parse().unwrap();
+        assert_eq!(parsed, march);
+        assert!("13".parse::<Month>().is_err());
+    }
+
+    #[test]
+    fn test_exact_name_fast_paths() {
+        for month in Month::all_months() {
+            assert_eq!(Month::from_en_exact(month.name_en), Some(*month));
+            assert_eq!(Month::from_ptbr_exact(month.name_ptbr), Some(*month));
+        }
+
+        // Only canonical case is accepted
+        assert_eq!(Month::from_en_exact("january"), None);
+        assert_eq!(Month::from_en_exact("JANUARY"), None);
+        assert_eq!(Month::from_en_exact("Jan"), None);
+        assert_eq!(Month::from_ptbr_exact("Marco"), None);
+        assert_eq!(Month::from_ptbr_exact("March"), None);
+    }
+
+    #[test]
+    fn test_try_from_conversions() {
+        let march: Month = 3u8.try_into().unwrap();
+        assert_eq!(march.month, 3);
+        assert_eq!(Month::try_from("março").unwrap(), march);
+        assert_eq!(Month::try_from("March".to_string()).unwrap(), march);
+
+        assert!(Month::try_from(13u8).is_err());
+        assert!(Month::try_from("Smarch").is_err());
+    }
+
+    #[test]
+    fn test_month_ordering() {
+        let march = Month::from_number(3).unwrap();
+        let october = Month::from_number(10).unwrap();
+        assert!(march < october);
+        assert!(october > march);
+        assert_eq!(march.max(october), october);
+
+        let mut months = vec![october, Month::from_number(1).unwrap(), march];
+        months.sort();
+        let numbers: Vec<u8> = months.iter().map(|m| m.month).collect();
+        assert_eq!(numbers, vec![1, 3, 10]);
+    }
+
+    #[test]
+    fn test_deserialize_validates() {
+        let month: Month = serde_json::from_str("3").unwrap();
+        assert_eq!(month, Month::from_number(3).unwrap());
+        let month: Month = serde_json::from_str(r#""Janeiro""#).unwrap();
+        assert_eq!(month.month, 1);
+        let month: Month = serde_json::from_str(r#""12""#).unwrap();
+        assert_eq!(month.month, 12);
+
+        assert!(serde_json::from_str::<Month>("13").is_err());
+        assert!(serde_json::from_str::<Month>("300").is_err());
+        assert!(serde_json::from_str::<Month>(r#""Smarch""#).is_err());
+
+        // Objects are rebuilt from the `month` field; inconsistent names are ignored
+        let month: Month = serde_json::from_str(r#"{"month": 2, "name_en": "July"}"#).unwrap();
+        assert_eq!(month.name_en, "February");
+        assert!(serde_json::from_str::<Month>(r#"{"month": 13, "text": "13", "name_en": "Smarch"}"#).is_err());
+        assert!(serde_json::from_str::<Month>(r#"{"name_en": "March"}"#).is_err());
+
+        let original = Month::from_number(7).unwrap();
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(serde_json::from_str::<Month>(&json).unwrap(), original);
+    }
+
+    #[test]
+    fn test_serialize_compact() {
+        let month = Month::from_number(1).unwrap();
+        assert_eq!(serde_json::to_string(&month).unwrap(), "1");
+        let months: Vec<Month> = serde_json::from_str("[1, 6, 12]").unwrap();
+        assert_eq!(serde_json::to_string(&months).unwrap(), "[1,6,12]");
+    }
+
+    #[test]
+    fn test_days_in_and_max_days() {
+        use crate::utils::time::Year;
+
+        let leap = Year::from_number(2024).unwrap();
+        let common = Year::from_number(2023).unwrap();
+        let feb = Month::from_number(2).unwrap();
+        let apr = Month::from_number(4).unwrap();
+        let dec = Month::from_number(12).unwrap();
+
+        assert_eq!(feb.days_in(&leap), 29);
+        assert_eq!(feb.days_in(&common), 28);
+        assert_eq!(apr.days_in(&common), 30);
+        assert_eq!(dec.days_in(&leap), 31);
+
+        assert_eq!(feb.max_days(), 29);
+        assert_eq!(apr.max_days(), 30);
+        assert_eq!(dec.max_days(), 31);
+
+        for month in Month::all_months() {
+            assert_eq!(month.days_in(&leap), month.max_days());
+        }
+    }
+
+    #[test]
+    fn test_quarter_and_semester() {
+        use crate::utils::time::{Quarter, Semester, Year};
+
+        let march = Month::from_number(3).unwrap();
+        assert_eq!(march.quarter(), Quarter::Q1);
+        assert_eq!(march.semester(), Semester::S1);
+
+        let quarters: Vec<u8> = Month::all_months().iter().map(|m| m.quarter().number()).collect();
+        assert_eq!(quarters, vec![1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]);
+        let semesters: Vec<u8> = Month::all_months().iter().map(|m| m.semester().number()).collect();
+        assert_eq!(semesters, vec![1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2]);
+
+        // Year::get_quarter forwards to Month::quarter
+        let year = Year::from_number(2024).unwrap();
+        for month in Month::all_months() {
+            assert_eq!(year.get_quarter(month), month.quarter().number());
+        }
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let month = |n: u8| Month::from_number(n).unwrap();
+
+        assert_eq!(month(3).checked_add(0), Some((month(3), 0)));
+        assert_eq!(month(3).checked_add(2), Some((month(5), 0)));
+        assert_eq!(month(12).checked_add(1), Some((month(1), 1)));
+        assert_eq!(month(1).checked_add(-1), Some((month(12), -1)));
+        assert_eq!(month(11).checked_add(26), Some((month(1), 3)));
+        assert_eq!(month(2).checked_add(-14), Some((month(12), -2)));
+        assert_eq!(month(1).checked_add(-12), Some((month(1), -1)));
+
+        // Extremes of i8 still resolve
+        assert_eq!(month(1).checked_add(i8::MAX), Some((month(8), 10)));
+        assert_eq!(month(1).checked_add(i8::MIN), Some((month(5), -11)));
+
+        // next/previous keep wrapping without reporting the year change
+        assert_eq!(month(12).next(), month(1));
+        assert_eq!(month(1).previous(), month(12));
+    }
+
+    #[test]
+    fn test_spanish_and_french_names_ignore_accents() {
+        assert_eq!(Month::from_french_name("fevrier").unwrap().month, 2);
+        assert_eq!(Month::from_french_name("aout").unwrap().month, 8);
+        assert_eq!(Month::from_french_name("decembre").unwrap().month, 12);
+        assert_eq!(Month::from_french_name("FÉVRIER").unwrap().month, 2);
+        assert!(Month::is_valid_french_name("aout"));
+        assert!(Month::is_valid_french_name("Decembre"));
+
+        // Accented input still matches the unaccented Spanish names
+        assert_eq!(Month::from_spanish_name("Séptiembre").unwrap().month, 9);
+        assert!(Month::is_valid_spanish_name("díciembre"));
+        assert!(!Month::is_valid_french_name("fevereiro"));
+    }
 }
\ No newline at end of file
@@ -19,6 +19,8 @@ impl MonthValidatable for str {
         Month::is_valid_month_text(self) ||
         Month::is_valid_english_name(self) ||
         Month::is_valid_portuguese_name(self) ||
+        Month::is_valid_spanish_name(self) ||
+        Month::is_valid_french_name(self) ||
         Month::is_valid_abbreviation(self) ||
         Month::is_valid_month_number_string(self)
     }
@@ -30,6 +32,8 @@ impl MonthValidatable for &str {
         Month::is_valid_month_text(self) ||
         Month::is_valid_english_name(self) ||
         Month::is_valid_portuguese_name(self) ||
+        Month::is_valid_spanish_name(self) ||
+        Month::is_valid_french_name(self) ||
         Month::is_valid_abbreviation(self) ||
         Month::is_valid_month_number_string(self)
     }
@@ -90,11 +94,21 @@ impl MonthFromInput for &str {
             return Ok(month);
         }
         
-        // 5. Portuguese name (last resort)
+        // 5. Portuguese name
         if let Ok(month) = Month::from_portuguese_name(self) {
             return Ok(month);
         }
         
+        // 6. Spanish name
+        if let Ok(month) = Month::from_spanish_name(self) {
+            return Ok(month);
+        }
+        
+        // 7. French name (last resort)
+        if let Ok(month) = Month::from_french_name(self) {
+            return Ok(month);
+        }
+        
         // This should never happen since validation passed, but just in case
         Err(UtilsError::Month(
             MonthError::cannot_parse_month(format!("Unable to parse '{}' as a month", self))
@@ -30,9 +30,18 @@ pub enum YearError {
     #[error("Invalid date for year {0}: {1}")]
     InvalidDate(i32, String),
     
+    #[error("Invalid date for year {0}: day must be at least 1")]
+    DayIsZero(i32),
+    
+    #[error("Invalid date: day {0} exceeds the {1} days in {2} {3}")]
+    DayExceedsMonth(u32, u8, String, i32),
+    
     #[error("Invalid quarter: {0}. Must be between 1 and 4")]
     InvalidQuarter(u8),
     
+    #[error("Invalid semester: {0}. Must be 1 or 2")]
+    InvalidSemester(u8),
+    
     #[error("Not a valid year: {0}. This value cannot be converted to a year.")]
     NotValidYear(String),
 }
@@ -66,10 +75,22 @@ impl YearError {
         Self::InvalidDate(year, msg.into())
     }
     
+    pub fn day_is_zero(year: i32) -> Self {
+        Self::DayIsZero(year)
+    }
+    
+    pub fn day_exceeds_month<S: Into<String>>(day: u32, days_in_month: u8, month: S, year: i32) -> Self {
+        Self::DayExceedsMonth(day, days_in_month, month.into(), year)
+    }
+    
     pub fn invalid_quarter(quarter: u8) -> Self {
         Self::InvalidQuarter(quarter)
     }
     
+    pub fn invalid_semester(semester: u8) -> Self {
+        Self::InvalidSemester(semester)
+    }
+    
     pub fn not_valid_year<S: Into<String>>(msg: S) -> Self {
         Self::NotValidYear(msg.into())
     }
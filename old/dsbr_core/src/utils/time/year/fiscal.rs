@@ -0,0 +1,48 @@
+use crate::core::Result;
+use crate::utils::time::{DateTime, Day, Month, Quarter, Year};
+
+/// A twelve-month fiscal year beginning on the 1st of `start_month` in `anchor_year`
+///
+/// Anchored at 2024 with an April start, it runs from 2024-04-01 through
+/// 2025-03-31 and its Q1 is April-June. A January start matches the
+/// calendar year and its quarters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalYear {
+    pub anchor_year: Year,
+    pub start_month: Month,
+}
+
+impl FiscalYear {
+    pub fn new(anchor_year: Year, start_month: Month) -> Self {
+        Self { anchor_year, start_month }
+    }
+
+    /// Get the first instant of the fiscal year
+    pub fn start(&self) -> Result<DateTime> {
+        DateTime::from_date_start_of_day(self.anchor_year, self.start_month, Day::from_number(1)?)
+    }
+
+    /// Check if a DateTime falls within this fiscal year
+    pub fn contains(&self, dt: &DateTime) -> bool {
+        self.month_offset(dt).is_some()
+    }
+
+    /// Get the fiscal quarter of a DateTime, counted from `start_month`
+    ///
+    /// Returns None when the DateTime is outside this fiscal year.
+    pub fn quarter_of(&self, dt: &DateTime) -> Option<Quarter> {
+        self.month_offset(dt).and_then(|offset| Quarter::try_from(offset / 3 + 1).ok())
+    }
+
+    // === Helper methods ===
+
+    /// Months from the fiscal year start to `dt`'s month (0-11), or None outside the year
+    fn month_offset(&self, dt: &DateTime) -> Option<u8> {
+        let months = |year: &Year, month: &Month| year.year * 12 + month.month as i32;
+        let offset = months(&dt.year, &dt.month) - months(&self.anchor_year, &self.start_month);
+        match offset {
+            0..=11 => Some(offset as u8),
+            _ => None,
+        }
+    }
+}
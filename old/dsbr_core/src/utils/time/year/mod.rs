@@ -1,21 +1,27 @@
 use dashmap::DashMap;
 use std::sync::LazyLock;
-use serde::{Serialize, Deserialize};
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Visitor;
 use chrono::{NaiveDate, DateTime, TimeZone, Datelike};
-use crate::core::Result;
+use crate::core::{Result, SharedError};
 use crate::utils::{UtilsError};
-use crate::utils::time::Month;
+use crate::utils::time::{Day, Month};
+use crate::utils::time::component_serde::{self, NumberedComponent};
 
 pub mod error;
+pub mod fiscal;
+pub mod period;
 pub mod traits;
 
 #[cfg(test)]
 mod tests;
 
 pub use error::{YearError, MIN_YEAR, MAX_YEAR, PIVOT_YEAR, CURRENT_CENTURY_START, PREVIOUS_CENTURY_START};
+pub use fiscal::FiscalYear;
+pub use period::{Quarter, Semester};
 pub use traits::{YearValidatable, YearFromInput};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub struct Year {
     pub year: i32,
     pub text_2d: &'static str,
@@ -39,10 +45,23 @@ pub static YEARS_ORDERED: LazyLock<Vec<Year>> = LazyLock::new(|| {
 });
 
 impl Year {
+    // Static lookup table for 2-digit text, indexed by `year % 100`
+    const TEXTS_2D: [&str; 100] = [
+        "00", "01", "02", "03", "04", "05", "06", "07", "08", "09",
+        "10", "11", "12", "13", "14", "15", "16", "17", "18", "19",
+        "20", "21", "22", "23", "24", "25", "26", "27", "28", "29",
+        "30", "31", "32", "33", "34", "35", "36", "37", "38", "39",
+        "40", "41", "42", "43", "44", "45", "46", "47", "48", "49",
+        "50", "51", "52", "53", "54", "55", "56", "57", "58", "59",
+        "60", "61", "62", "63", "64", "65", "66", "67", "68", "69",
+        "70", "71", "72", "73", "74", "75", "76", "77", "78", "79",
+        "80", "81", "82", "83", "84", "85", "86", "87", "88", "89",
+        "90", "91", "92", "93", "94", "95", "96", "97", "98", "99",
+    ];
+    
     /// Create a new Year without validation (internal use only)
     fn new_unchecked(year: i32) -> Self {
-        // Efficient 2-digit text generation using format
-        let text_2d = Box::leak(format!("{:02}", year % 100).into_boxed_str());
+        let text_2d = Self::TEXTS_2D[(year % 100) as usize];
         
         // For 4-digit text, we need to handle the full range
         let text_4d = Box::leak(year.to_string().into_boxed_str());
@@ -95,7 +114,7 @@ impl Year {
     /// Find year by number (1900-2100)
     pub fn from_number(year: i32) -> Result<Year> {
         match year.is_valid_year() {
-            true => Ok(*YEARS.get(&year).unwrap()),
+            true => Ok(YEARS_ORDERED[(year - MIN_YEAR) as usize]),
             false => Err(UtilsError::Year(
                 YearError::invalid_year(year)
             ).into()),
@@ -156,6 +175,30 @@ impl Year {
         self.add_years(-years)
     }
     
+    /// Add years, returning `None` when the result leaves MIN_YEAR..=MAX_YEAR
+    pub fn checked_add_years(&self, years: i32) -> Option<Year> {
+        self.year
+            .checked_add(years)
+            .filter(|year| (MIN_YEAR..=MAX_YEAR).contains(year))
+            .map(|year| Self::all_years()[(year - MIN_YEAR) as usize])
+    }
+    
+    /// Subtract years, returning `None` when the result leaves MIN_YEAR..=MAX_YEAR
+    pub fn checked_sub_years(&self, years: i32) -> Option<Year> {
+        self.checked_add_years(years.checked_neg()?)
+    }
+    
+    /// Add years, clamping the result to MIN_YEAR..=MAX_YEAR
+    pub fn saturating_add_years(&self, years: i32) -> Year {
+        let year = self.year.saturating_add(years).clamp(MIN_YEAR, MAX_YEAR);
+        Self::all_years()[(year - MIN_YEAR) as usize]
+    }
+    
+    /// Subtract years, clamping the result to MIN_YEAR..=MAX_YEAR
+    pub fn saturating_sub_years(&self, years: i32) -> Year {
+        self.saturating_add_years(years.saturating_neg())
+    }
+    
     /// Check if this year comes before another chronologically
     pub fn is_before(&self, other: &Year) -> bool {
         self.year < other.year
@@ -178,6 +221,38 @@ impl Year {
         self.year - other.year
     }
     
+    // === Decade and century ranges ===
+    
+    /// Get the first year of this year's decade (2023 -> 2020)
+    pub fn decade_start(&self) -> Result<Year> {
+        Year::from_number(self.year - self.year % 10)
+    }
+    
+    /// Get the last year of this year's decade (2023 -> 2029), clamped to MAX_YEAR
+    pub fn decade_end(&self) -> Result<Year> {
+        Year::from_number((self.year - self.year % 10 + 9).min(MAX_YEAR))
+    }
+    
+    /// Get the first year of this year's century, using the `century` field (1903 -> 1900)
+    pub fn century_start(&self) -> Result<Year> {
+        Year::from_number(self.century as i32 * 100)
+    }
+    
+    /// Get the last year of this year's century (1903 -> 1999), clamped to MAX_YEAR
+    pub fn century_end(&self) -> Result<Year> {
+        Year::from_number((self.century as i32 * 100 + 99).min(MAX_YEAR))
+    }
+    
+    /// Check whether both years fall in the same decade
+    pub fn same_decade_as(&self, other: &Year) -> bool {
+        self.year / 10 == other.year / 10
+    }
+    
+    /// Check whether both years fall in the same century
+    pub fn same_century_as(&self, other: &Year) -> bool {
+        self.century == other.century
+    }
+    
     /// Convert to 2-digit string format ("00", "01", etc.)
     pub fn to_2digit_string(&self) -> String {
         self.text_2d.to_string()
@@ -203,11 +278,46 @@ impl Year {
         self.text_4d
     }
     
+    /// Write the 2-digit text into any `fmt::Write` target without allocating
+    pub fn write_2digit(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        w.write_str(self.text_2d)
+    }
+    
+    /// Write the 4-digit text into any `fmt::Write` target without allocating
+    pub fn write_4digit(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+        w.write_str(self.text_4d)
+    }
+    
     /// Check if this is a leap year
     pub fn is_leap_year(&self) -> bool {
         self.is_leap
     }
     
+    /// Get every leap year from `start` to `end` (inclusive); empty if `start > end`
+    pub fn leap_years_between(start: Year, end: Year) -> Vec<Year> {
+        match start.year <= end.year {
+            true => Self::all_years()[(start.year - MIN_YEAR) as usize..=(end.year - MIN_YEAR) as usize]
+                .iter()
+                .filter(|year| year.is_leap)
+                .copied()
+                .collect(),
+            false => Vec::new(),
+        }
+    }
+    
+    /// Count the leap years from `start` to `end` (inclusive) in O(1); zero if `start > end`
+    pub fn count_leap_years_between(start: Year, end: Year) -> u32 {
+        // Leap years in 1..=year under the Gregorian rules
+        fn leap_years_through(year: i32) -> i32 {
+            year / 4 - year / 100 + year / 400
+        }
+        
+        match start.year <= end.year {
+            true => (leap_years_through(end.year) - leap_years_through(start.year - 1)) as u32,
+            false => 0,
+        }
+    }
+    
     /// Get the number of days in this year (365 or 366)
     pub fn days_in_year(&self) -> u16 {
         match self.is_leap {
@@ -229,6 +339,42 @@ impl Year {
         }
     }
     
+    /// Get the day of the year (1-366) for a month and day in this year
+    pub fn ordinal_day(&self, month: &Month, day: &Day) -> u16 {
+        let days_before: u16 = Month::all_months()[..(month.month - 1) as usize]
+            .iter()
+            .map(|m| self.days_in_month(m) as u16)
+            .sum();
+        days_before + day.day as u16
+    }
+    
+    /// Convert a day of the year (1-366) back to its month and day
+    ///
+    /// Day 366 is only valid in leap years.
+    pub fn from_ordinal_day(&self, ordinal: u16) -> Result<(Month, Day)> {
+        if ordinal == 0 || ordinal > self.days_in_year() {
+            return Err(UtilsError::Year(YearError::invalid_date(
+                self.year,
+                format!("Day of year {} must be between 1 and {}", ordinal, self.days_in_year())
+            )).into());
+        }
+        
+        let mut remaining = ordinal;
+        for month in Month::all_months() {
+            let month_len = self.days_in_month(month) as u16;
+            match remaining <= month_len {
+                true => return Ok((*month, Day::from_number(remaining as u8)?)),
+                false => remaining -= month_len,
+            }
+        }
+        unreachable!("ordinal was checked against days_in_year")
+    }
+    
+    /// Iterate over every valid day of a month in this year
+    pub fn iter_days_in_month(&self, month: &Month) -> impl DoubleEndedIterator<Item = Day> + ExactSizeIterator + use<> {
+        Day::iter_in_month(month, self)
+    }
+    
     /// Get the first day of the year (January 1st)
     pub fn year_start(&self) -> Result<NaiveDate> {
         NaiveDate::from_ymd_opt(self.year, 1, 1)
@@ -308,42 +454,39 @@ impl Year {
         self.to_datetime_with_timezone(&dec, 31, 23, 59, 59, tz)
     }
     
-    /// Get the first day of a specific quarter
+    /// Get the first day of a specific quarter (1-4)
     pub fn quarter_start(&self, quarter: u8) -> Result<NaiveDate> {
-        let month_num = match quarter {
-            1 => 1,
-            2 => 4,
-            3 => 7,
-            4 => 10,
-            _ => return Err(UtilsError::Year(
-                YearError::invalid_quarter(quarter)
-            ).into()),
-        };
-        
-        let month = Month::from_number(month_num).map_err(|e| UtilsError::Year(
-            YearError::chrono_conversion(format!("Cannot get month {}: {}", month_num, e))
-        ))?;
-        
-        self.to_naive_date(&month, 1)
+        self.start_of_quarter(Quarter::try_from(quarter)?)
     }
     
-    /// Get the last day of a specific quarter
+    /// Get the last day of a specific quarter (1-4)
     pub fn quarter_end(&self, quarter: u8) -> Result<NaiveDate> {
-        let (month_num, day) = match quarter {
-            1 => (3, 31),
-            2 => (6, 30),
-            3 => (9, 30),
-            4 => (12, 31),
-            _ => return Err(UtilsError::Year(
-                YearError::invalid_quarter(quarter)
-            ).into()),
-        };
-        
-        let month = Month::from_number(month_num).map_err(|e| UtilsError::Year(
-            YearError::chrono_conversion(format!("Cannot get month {}: {}", month_num, e))
-        ))?;
-        
-        self.to_naive_date(&month, day)
+        self.end_of_quarter(Quarter::try_from(quarter)?)
+    }
+    
+    /// Get the first day of a quarter
+    pub fn start_of_quarter(&self, quarter: Quarter) -> Result<NaiveDate> {
+        self.month_start(&quarter.start_month())
+    }
+    
+    /// Get the last day of a quarter
+    pub fn end_of_quarter(&self, quarter: Quarter) -> Result<NaiveDate> {
+        self.month_end(&quarter.end_month())
+    }
+    
+    /// Get the first day of a semester
+    pub fn start_of_semester(&self, semester: Semester) -> Result<NaiveDate> {
+        self.month_start(&semester.start_month())
+    }
+    
+    /// Get the last day of a semester
+    pub fn end_of_semester(&self, semester: Semester) -> Result<NaiveDate> {
+        self.month_end(&semester.end_month())
+    }
+    
+    /// Get the first instant of the fiscal year starting in `start_month` of this year
+    pub fn fiscal_year_start(&self, start_month: Month) -> Result<crate::utils::time::DateTime> {
+        FiscalYear::new(*self, start_month).start()
     }
     
     /// Get the first day of a specific month in this year
@@ -359,12 +502,18 @@ impl Year {
     
     /// Check if a specific date (month and day) is valid for this year
     pub fn is_valid_date(&self, month: &Month, day: u32) -> bool {
+        self.validate_date(month, day).is_ok()
+    }
+    
+    /// Validate a specific date (month and day) for this year, explaining why it is invalid
+    pub fn validate_date(&self, month: &Month, day: u32) -> Result<()> {
+        let max_days = self.days_in_month(month);
         match day {
-            0 => false,
-            d => {
-                let max_days = self.days_in_month(month) as u32;
-                d <= max_days
-            }
+            0 => Err(UtilsError::Year(YearError::day_is_zero(self.year)).into()),
+            d if d > max_days as u32 => Err(UtilsError::Year(
+                YearError::day_exceeds_month(d, max_days, month.name_en, self.year)
+            ).into()),
+            _ => Ok(()),
         }
     }
     
@@ -380,40 +529,99 @@ impl Year {
             .collect()
     }
     
-    /// Get the quarter number (1-4) for a given month
+    /// Get the quarter number (1-4) for a given month (forwards to `Month::quarter`)
     pub fn get_quarter(&self, month: &Month) -> u8 {
-        match month.month {
-            1..=3 => 1,
-            4..=6 => 2,
-            7..=9 => 3,
-            10..=12 => 4,
-            _ => unreachable!("Invalid month number"),
-        }
+        month.quarter().number()
     }
     
-    /// Get all months in a specific quarter
+    /// Get all months in a specific quarter (1-4)
     pub fn get_quarter_months(&self, quarter: u8) -> Result<Vec<Month>> {
-        let month_range = match quarter {
-            1 => 1..=3,
-            2 => 4..=6,
-            3 => 7..=9,
-            4 => 10..=12,
-            _ => return Err(UtilsError::Year(
-                YearError::invalid_quarter(quarter)
-            ).into()),
-        };
-        
-        month_range
-            .map(|month_num| {
-                Month::from_number(month_num).map_err(|e| UtilsError::Year(
-                    YearError::chrono_conversion(format!("Cannot get month {}: {}", month_num, e))
-                ).into())
-            })
-            .collect()
+        Ok(Quarter::try_from(quarter)?.months().to_vec())
     }
     
     /// Public generic validation method - accepts different year types
     pub fn is_valid<T: YearValidatable>(input: T) -> bool {
         input.is_valid_year()
     }
-}
\ No newline at end of file
+}
+
+// === Display / FromStr implementations ===
+impl std::fmt::Display for Year {
+    /// Four-digit year: "2024"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text_4d)
+    }
+}
+
+impl std::str::FromStr for Year {
+    type Err = SharedError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Year::from(s)
+    }
+}
+
+// === TryFrom implementations ===
+impl TryFrom<i32> for Year {
+    type Error = SharedError;
+
+    fn try_from(value: i32) -> Result<Self> {
+        Year::from(value)
+    }
+}
+
+impl TryFrom<&str> for Year {
+    type Error = SharedError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Year::from(value)
+    }
+}
+
+impl TryFrom<String> for Year {
+    type Error = SharedError;
+
+    fn try_from(value: String) -> Result<Self> {
+        Year::from(value)
+    }
+}
+
+// === Serde implementations ===
+impl Serialize for Year {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.year)
+    }
+}
+
+impl<'de> Deserialize<'de> for Year {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        component_serde::deserialize(deserializer)
+    }
+}
+
+impl NumberedComponent for Year {
+    const FIELD: &'static str = "year";
+
+    fn expecting(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a year between {} and {} as an integer, a string, or an object with a `year` field", MIN_YEAR, MAX_YEAR)
+    }
+
+    fn from_wire_number(value: i64) -> Result<Self> {
+        match i32::try_from(value) {
+            Ok(number) => Year::from(number),
+            Err(_) => Err(UtilsError::Year(YearError::not_valid_year(value.to_string())).into()),
+        }
+    }
+
+    fn from_wire_str(value: &str) -> Result<Self> {
+        Year::from(value)
+    }
+
+    fn deserialize_number<'de, D, V>(deserializer: D, visitor: V) -> std::result::Result<V::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Visitor<'de>,
+    {
+        deserializer.deserialize_i32(visitor)
+    }
+}
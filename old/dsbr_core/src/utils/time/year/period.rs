@@ -0,0 +1,161 @@
+use serde::{Serialize, Deserialize};
+use crate::core::{Result, SharedError};
+use crate::utils::{UtilsError};
+use crate::utils::time::Month;
+use crate::utils::time::year::YearError;
+
+/// Quarter of a year (Q1 = Jan-Mar, ..., Q4 = Oct-Dec)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Quarter {
+    Q1,
+    Q2,
+    Q3,
+    Q4,
+}
+
+impl Quarter {
+    /// Get the quarter number (1-4)
+    pub fn number(&self) -> u8 {
+        match self {
+            Quarter::Q1 => 1,
+            Quarter::Q2 => 2,
+            Quarter::Q3 => 3,
+            Quarter::Q4 => 4,
+        }
+    }
+
+    /// Get the quarter a month falls in
+    pub fn from_month(month: &Month) -> Self {
+        match month.month {
+            1..=3 => Quarter::Q1,
+            4..=6 => Quarter::Q2,
+            7..=9 => Quarter::Q3,
+            _ => Quarter::Q4,
+        }
+    }
+
+    /// Get the first month of this quarter
+    pub fn start_month(&self) -> Month {
+        Month::all_months()[(self.number() as usize - 1) * 3]
+    }
+
+    /// Get the last month of this quarter
+    pub fn end_month(&self) -> Month {
+        Month::all_months()[(self.number() as usize - 1) * 3 + 2]
+    }
+
+    /// Get the three months of this quarter in order
+    pub fn months(&self) -> [Month; 3] {
+        let start = (self.number() as usize - 1) * 3;
+        let months = Month::all_months();
+        [months[start], months[start + 1], months[start + 2]]
+    }
+
+    /// Get the semester this quarter belongs to
+    pub fn semester(&self) -> Semester {
+        match self {
+            Quarter::Q1 | Quarter::Q2 => Semester::S1,
+            Quarter::Q3 | Quarter::Q4 => Semester::S2,
+        }
+    }
+}
+
+impl From<Quarter> for u8 {
+    fn from(quarter: Quarter) -> u8 {
+        quarter.number()
+    }
+}
+
+impl TryFrom<u8> for Quarter {
+    type Error = SharedError;
+
+    fn try_from(quarter: u8) -> Result<Self> {
+        match quarter {
+            1 => Ok(Quarter::Q1),
+            2 => Ok(Quarter::Q2),
+            3 => Ok(Quarter::Q3),
+            4 => Ok(Quarter::Q4),
+            _ => Err(UtilsError::Year(YearError::invalid_quarter(quarter)).into()),
+        }
+    }
+}
+
+impl std::fmt::Display for Quarter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Q{}", self.number())
+    }
+}
+
+/// Half of a year (S1 = Jan-Jun, S2 = Jul-Dec)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Semester {
+    S1,
+    S2,
+}
+
+impl Semester {
+    /// Get the semester number (1-2)
+    pub fn number(&self) -> u8 {
+        match self {
+            Semester::S1 => 1,
+            Semester::S2 => 2,
+        }
+    }
+
+    /// Get the semester a month falls in
+    pub fn from_month(month: &Month) -> Self {
+        match month.month {
+            1..=6 => Semester::S1,
+            _ => Semester::S2,
+        }
+    }
+
+    /// Get the first month of this semester
+    pub fn start_month(&self) -> Month {
+        Month::all_months()[(self.number() as usize - 1) * 6]
+    }
+
+    /// Get the last month of this semester
+    pub fn end_month(&self) -> Month {
+        Month::all_months()[(self.number() as usize - 1) * 6 + 5]
+    }
+
+    /// Get the six months of this semester in order
+    pub fn months(&self) -> [Month; 6] {
+        let start = (self.number() as usize - 1) * 6;
+        let months = Month::all_months();
+        std::array::from_fn(|i| months[start + i])
+    }
+
+    /// Get the two quarters of this semester in order
+    pub fn quarters(&self) -> [Quarter; 2] {
+        match self {
+            Semester::S1 => [Quarter::Q1, Quarter::Q2],
+            Semester::S2 => [Quarter::Q3, Quarter::Q4],
+        }
+    }
+}
+
+impl From<Semester> for u8 {
+    fn from(semester: Semester) -> u8 {
+        semester.number()
+    }
+}
+
+impl TryFrom<u8> for Semester {
+    type Error = SharedError;
+
+    fn try_from(semester: u8) -> Result<Self> {
+        match semester {
+            1 => Ok(Semester::S1),
+            2 => Ok(Semester::S2),
+            _ => Err(UtilsError::Year(YearError::invalid_semester(semester)).into()),
+        }
+    }
+}
+
+impl std::fmt::Display for Semester {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "S{}", self.number())
+    }
+}
@@ -524,4 +524,304 @@ mod tests {
         assert!(!Year::is_valid("1800"));
         assert!(!Year::is_valid("2200"));
     }
+
+    #[test]
+    fn test_display_and_from_str() {
+        let year = Year::from_number(2024).unwrap();
+        assert_eq!(year.to_string(), "2024");
+        assert_eq!(format!("{}", Year::from_number(1999).unwrap()), "1999");
+
+        let parsed: Year = "2024".parse().unwrap();
+        assert_eq!(parsed, year);
+        assert!("abcd".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn test_validate_date() {
+        let leap = Year::from_number(2024).unwrap();
+        let common = Year::from_number(2023).unwrap();
+        let feb = Month::from_number(2).unwrap();
+        let april = Month::from_number(4).unwrap();
+
+        assert!(leap.validate_date(&feb, 29).is_ok());
+        assert!(common.validate_date(&april, 30).is_ok());
+
+        let err = leap.validate_date(&feb, 0).unwrap_err().to_string();
+        assert!(err.contains("at least 1"), "unexpected error: {}", err);
+
+        let err = common.validate_date(&feb, 29).unwrap_err().to_string();
+        assert!(err.contains("day 29 exceeds the 28 days in February 2023"), "unexpected error: {}", err);
+
+        let err = common.validate_date(&april, 31).unwrap_err().to_string();
+        assert!(err.contains("day 31 exceeds the 30 days in April 2023"), "unexpected error: {}", err);
+
+        // The bool helper agrees with the Result-based one
+        assert!(!common.is_valid_date(&feb, 29));
+        assert!(!common.is_valid_date(&feb, 0));
+    }
+
+    #[test]
+    fn test_quarter_enum() {
+        use crate::utils::time::Quarter;
+
+        assert_eq!(Quarter::try_from(3).unwrap(), Quarter::Q3);
+        assert!(Quarter::try_from(0).is_err());
+        assert!(Quarter::try_from(5).is_err());
+        assert_eq!(u8::from(Quarter::Q4), 4);
+
+        assert_eq!(Quarter::Q2.start_month().month, 4);
+        assert_eq!(Quarter::Q2.end_month().month, 6);
+        let months: Vec<u8> = Quarter::Q4.months().iter().map(|m| m.month).collect();
+        assert_eq!(months, vec![10, 11, 12]);
+
+        assert_eq!(Quarter::from_month(&Month::from_number(8).unwrap()), Quarter::Q3);
+        assert_eq!(Quarter::Q1.to_string(), "Q1");
+        assert!(Quarter::Q1 < Quarter::Q4);
+    }
+
+    #[test]
+    fn test_semester_enum() {
+        use crate::utils::time::{Quarter, Semester};
+
+        assert_eq!(Semester::try_from(2).unwrap(), Semester::S2);
+        assert!(Semester::try_from(3).is_err());
+        assert_eq!(u8::from(Semester::S1), 1);
+
+        assert_eq!(Semester::S2.start_month().month, 7);
+        assert_eq!(Semester::S2.end_month().month, 12);
+        let months: Vec<u8> = Semester::S1.months().iter().map(|m| m.month).collect();
+        assert_eq!(months, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(Semester::S2.quarters(), [Quarter::Q3, Quarter::Q4]);
+        assert_eq!(Quarter::Q2.semester(), Semester::S1);
+
+        assert_eq!(Semester::from_month(&Month::from_number(6).unwrap()), Semester::S1);
+        assert_eq!(Semester::S2.to_string(), "S2");
+    }
+
+    #[test]
+    fn test_year_typed_quarter_and_semester_bounds() {
+        use crate::utils::time::{Quarter, Semester};
+
+        let year = Year::from_number(2024).unwrap();
+        assert_eq!(year.start_of_quarter(Quarter::Q2).unwrap(), NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
+        assert_eq!(year.end_of_quarter(Quarter::Q1).unwrap(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+        assert_eq!(year.start_of_semester(Semester::S2).unwrap(), NaiveDate::from_ymd_opt(2024, 7, 1).unwrap());
+        assert_eq!(year.end_of_semester(Semester::S1).unwrap(), NaiveDate::from_ymd_opt(2024, 6, 30).unwrap());
+
+        // u8 versions agree with the typed ones
+        assert_eq!(year.quarter_start(3).unwrap(), year.start_of_quarter(Quarter::Q3).unwrap());
+        assert_eq!(year.quarter_end(4).unwrap(), year.end_of_quarter(Quarter::Q4).unwrap());
+        assert!(year.quarter_start(5).is_err());
+    }
+
+    #[test]
+    fn test_write_digit_forms() {
+        let year = Year::from_number(2007).unwrap();
+
+        let mut out = String::new();
+        year.write_4digit(&mut out).unwrap();
+        out.push('/');
+        year.write_2digit(&mut out).unwrap();
+        assert_eq!(out, "2007/07");
+
+        // Matches the allocating helpers
+        let mut four = String::new();
+        year.write_4digit(&mut four).unwrap();
+        assert_eq!(four, year.to_4digit_string());
+        let mut two = String::new();
+        year.write_2digit(&mut two).unwrap();
+        assert_eq!(two, year.to_2digit_string());
+    }
+
+    #[test]
+    fn test_ordinal_day_round_trip() {
+        let leap = Year::from_number(2024).unwrap();
+        let common = Year::from_number(2023).unwrap();
+
+        let (month, day) = leap.from_ordinal_day(60).unwrap();
+        assert_eq!((month.month, day.day), (2, 29));
+        let (month, day) = common.from_ordinal_day(60).unwrap();
+        assert_eq!((month.month, day.day), (3, 1));
+
+        let (month, day) = leap.from_ordinal_day(366).unwrap();
+        assert_eq!((month.month, day.day), (12, 31));
+        assert!(common.from_ordinal_day(366).is_err());
+        assert!(leap.from_ordinal_day(0).is_err());
+        assert!(leap.from_ordinal_day(367).is_err());
+
+        for ordinal in 1..=leap.days_in_year() {
+            let (month, day) = leap.from_ordinal_day(ordinal).unwrap();
+            assert_eq!(leap.ordinal_day(&month, &day), ordinal);
+        }
+    }
+
+    #[test]
+    fn test_try_from_conversions() {
+        let year: Year = 2024i32.try_into().unwrap();
+        assert_eq!(year.year, 2024);
+        assert_eq!(Year::try_from("2024").unwrap(), year);
+        assert_eq!(Year::try_from("2024".to_string()).unwrap(), year);
+
+        assert!(Year::try_from(1800i32).is_err());
+        assert!(Year::try_from("not a year").is_err());
+    }
+
+    #[test]
+    fn test_decade_and_century_ranges() {
+        let y2023 = Year::from_number(2023).unwrap();
+        assert_eq!(y2023.decade_start().unwrap().year, 2020);
+        assert_eq!(y2023.decade_end().unwrap().year, 2029);
+        assert_eq!(y2023.century_start().unwrap().year, 2000);
+        assert_eq!(y2023.century_end().unwrap().year, 2099);
+
+        let y1903 = Year::from_number(1903).unwrap();
+        assert_eq!(y1903.century_start().unwrap().year, 1900);
+        assert_eq!(y1903.century_end().unwrap().year, 1999);
+        assert_eq!(y1903.decade_start().unwrap().year, 1900);
+
+        // Boundaries at the supported range edges are clamped to MAX_YEAR
+        let y2100 = Year::from_number(2100).unwrap();
+        assert_eq!(y2100.decade_start().unwrap().year, 2100);
+        assert_eq!(y2100.decade_end().unwrap().year, 2100);
+        assert_eq!(y2100.century_start().unwrap().year, 2100);
+        assert_eq!(y2100.century_end().unwrap().year, 2100);
+
+        assert!(y2023.same_decade_as(&Year::from_number(2020).unwrap()));
+        assert!(!y2023.same_decade_as(&Year::from_number(2030).unwrap()));
+        assert!(!Year::from_number(1999).unwrap().same_decade_as(&Year::from_number(2000).unwrap()));
+        assert!(y2023.same_century_as(&Year::from_number(2099).unwrap()));
+        assert!(!y2023.same_century_as(&y1903));
+    }
+
+    #[test]
+    fn test_checked_and_saturating_year_arithmetic() {
+        let year = Year::from_number(2090).unwrap();
+
+        assert_eq!(year.checked_add_years(5).unwrap().year, 2095);
+        assert_eq!(year.checked_add_years(10).unwrap().year, 2100);
+        assert!(year.checked_add_years(11).is_none());
+        assert!(year.checked_add_years(i32::MAX).is_none());
+        assert_eq!(year.checked_sub_years(190).unwrap().year, 1900);
+        assert!(year.checked_sub_years(191).is_none());
+        assert!(year.checked_sub_years(i32::MIN).is_none());
+
+        assert_eq!(year.saturating_add_years(5).year, 2095);
+        assert_eq!(year.saturating_add_years(50).year, 2100);
+        assert_eq!(year.saturating_add_years(i32::MAX).year, 2100);
+        assert_eq!(year.saturating_sub_years(500).year, 1900);
+        assert_eq!(year.saturating_sub_years(i32::MIN).year, 2100);
+        assert_eq!(year.saturating_add_years(-500).year, 1900);
+
+        // Result-returning versions are unchanged
+        assert!(year.add_years(11).is_err());
+        assert_eq!(year.add_years(5).unwrap(), year.checked_add_years(5).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_validates() {
+        let year: Year = serde_json::from_str("2024").unwrap();
+        assert_eq!(year, Year::from_number(2024).unwrap());
+
+        // Strings go through the 2-digit pivot logic
+        let year: Year = serde_json::from_str("\"23\"").unwrap();
+        assert_eq!(year.year, 2023);
+        let year: Year = serde_json::from_str("\"1999\"").unwrap();
+        assert_eq!(year.year, 1999);
+
+        assert!(serde_json::from_str::<Year>("1850").is_err());
+        assert!(serde_json::from_str::<Year>("\"abcd\"").is_err());
+        assert!(serde_json::from_str::<Year>("-5").is_err());
+
+        // Objects are rebuilt from the `year` field; other fields are ignored
+        let year: Year = serde_json::from_str(r#"{"year": 2024, "is_leap": false, "text_4d": "1234"}"#).unwrap();
+        assert_eq!(year, Year::from_number(2024).unwrap());
+        assert!(year.is_leap);
+        assert!(serde_json::from_str::<Year>(r#"{"year": 1850}"#).is_err());
+        assert!(serde_json::from_str::<Year>(r#"{"text_4d": "2024"}"#).is_err());
+
+        // Current serialized form still round-trips
+        let original = Year::from_number(2007).unwrap();
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(serde_json::from_str::<Year>(&json).unwrap(), original);
+    }
+
+    #[test]
+    fn test_serialize_compact() {
+        let year = Year::from_number(2024).unwrap();
+        assert_eq!(serde_json::to_string(&year).unwrap(), "2024");
+        // The 2-digit text form is not used on the wire
+        let year = Year::from_number(1999).unwrap();
+        assert_eq!(serde_json::to_string(&year).unwrap(), "1999");
+    }
+
+    #[test]
+    fn test_leap_years_between() {
+        let year = |n: i32| Year::from_number(n).unwrap();
+
+        let leaps = Year::leap_years_between(year(1900), year(1912));
+        let numbers: Vec<i32> = leaps.iter().map(|y| y.year).collect();
+        assert_eq!(numbers, vec![1904, 1908, 1912]); // 1900 is not a leap year
+        assert_eq!(Year::count_leap_years_between(year(1900), year(1912)), 3);
+
+        // 2000 is a leap year, 2100 is not
+        assert_eq!(Year::count_leap_years_between(year(2000), year(2000)), 1);
+        assert_eq!(Year::count_leap_years_between(year(2100), year(2100)), 0);
+        assert_eq!(Year::count_leap_years_between(year(2023), year(2023)), 0);
+
+        // Reversed ranges are empty
+        assert!(Year::leap_years_between(year(2024), year(2020)).is_empty());
+        assert_eq!(Year::count_leap_years_between(year(2024), year(2020)), 0);
+
+        // The O(1) count agrees with the list for a spread of ranges
+        for (start, end) in [(1900, 2100), (1901, 1903), (1999, 2001), (2020, 2024), (2097, 2100), (1950, 2050)] {
+            assert_eq!(
+                Year::count_leap_years_between(year(start), year(end)) as usize,
+                Year::leap_years_between(year(start), year(end)).len(),
+                "{}..={}", start, end
+            );
+        }
+        assert_eq!(Year::count_leap_years_between(year(1900), year(2100)), 49);
+    }
+
+    #[test]
+    fn test_fiscal_year() {
+        use crate::utils::time::{DateTime, FiscalYear, Month, Quarter};
+
+        let year = Year::from_number(2024).unwrap();
+        let april = Month::from_number(4).unwrap();
+        let dt = |input: &str| DateTime::from_yyyy_mm_dd(input).unwrap();
+
+        assert_eq!(year.fiscal_year_start(april).unwrap(), dt("2024-04-01"));
+
+        let fiscal = FiscalYear::new(year, april);
+        assert_eq!(fiscal.start().unwrap(), dt("2024-04-01"));
+        assert!(fiscal.contains(&dt("2024-04-01")));
+        assert!(fiscal.contains(&dt("2025-03-31")));
+        assert!(!fiscal.contains(&dt("2024-03-31")));
+        assert!(!fiscal.contains(&dt("2025-04-01")));
+
+        assert_eq!(fiscal.quarter_of(&dt("2024-04-15")), Some(Quarter::Q1));
+        assert_eq!(fiscal.quarter_of(&dt("2024-09-30")), Some(Quarter::Q2));
+        assert_eq!(fiscal.quarter_of(&dt("2024-12-31")), Some(Quarter::Q3));
+        assert_eq!(fiscal.quarter_of(&dt("2025-02-01")), Some(Quarter::Q4));
+        assert_eq!(fiscal.quarter_of(&dt("2024-01-15")), None);
+
+        // A January start matches the calendar quarters
+        let calendar = FiscalYear::new(year, Month::from_number(1).unwrap());
+        for month in 1..=12u8 {
+            let date = DateTime::from_yyyy_mm_dd(&format!("2024-{:02}-10", month)).unwrap();
+            assert_eq!(calendar.quarter_of(&date).map(|q| q.number()), Some(date.quarter()));
+        }
+    }
+
+    #[test]
+    fn test_text_2d_table_covers_every_year() {
+        for year in Year::all_years() {
+            assert_eq!(year.text_2d, format!("{:02}", year.year % 100));
+            assert_eq!(year.to_2digit_text(), year.text_2d);
+        }
+        assert_eq!(Year::from_number(1900).unwrap().text_2d, "00");
+        assert_eq!(Year::from_number(2100).unwrap().text_2d, "00");
+        assert_eq!(Year::from_number(1999).unwrap().text_2d, "99");
+    }
 }
\ No newline at end of file